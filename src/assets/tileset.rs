@@ -20,6 +20,17 @@ static REGIONS: phf::Map<&'static str, Rectangle> = phf::phf_map! {
     "boulder_middle" => Rectangle::new(60.0, 10.0, 10.0, 10.0),
     "boulder_small" => Rectangle::new(70.0, 10.0, 10.0, 10.0),
     "pit" => Rectangle::new(80.0, 10.0, 10.0, 10.0),
+    "mud" => Rectangle::new(90.0, 10.0, 10.0, 10.0),
+    "snow" => Rectangle::new(90.0, 20.0, 10.0, 10.0),
+    "deep_water1" => Rectangle::new(0.0, 80.0, 10.0, 10.0),
+    "deep_water2" => Rectangle::new(10.0, 80.0, 10.0, 10.0),
+    "wooden_wall" => Rectangle::new(20.0, 80.0, 10.0, 10.0),
+    "wooden_floor" => Rectangle::new(30.0, 80.0, 10.0, 10.0),
+    "shrine" => Rectangle::new(40.0, 80.0, 10.0, 10.0),
+    "ashes" => Rectangle::new(50.0, 80.0, 10.0, 10.0),
+    "shallow_water1" => Rectangle::new(60.0, 80.0, 10.0, 10.0),
+    "shallow_water2" => Rectangle::new(70.0, 80.0, 10.0, 10.0),
+    "campfire" => Rectangle::new(80.0, 80.0, 10.0, 10.0),
     "grass1" => Rectangle::new(0.0, 20.0, 10.0, 10.0),
     "grass2" => Rectangle::new(10.0, 20.0, 10.0, 10.0),
     "grass3" => Rectangle::new(20.0, 20.0, 10.0, 10.0),
@@ -57,10 +68,24 @@ static REGIONS: phf::Map<&'static str, Rectangle> = phf::phf_map! {
     "cloak" => Rectangle::new(30.0, 70.0, 10.0, 10.0),
     "rags" => Rectangle::new(40.0, 70.0, 10.0, 10.0),
     "hat" => Rectangle::new(40.0, 70.0, 10.0, 10.0),
+    "backpack" => Rectangle::new(40.0, 70.0, 10.0, 10.0),
+    "pouch" => Rectangle::new(40.0, 70.0, 10.0, 10.0),
+    "leather_armor" => Rectangle::new(40.0, 70.0, 10.0, 10.0),
+    "torch" => Rectangle::new(60.0, 70.0, 10.0, 10.0),
+    "bow" => Rectangle::new(50.0, 0.0, 10.0, 10.0),
+    "sling" => Rectangle::new(60.0, 0.0, 10.0, 10.0),
+    "arrow" => Rectangle::new(70.0, 0.0, 10.0, 10.0),
     "mt" => Rectangle::new(0.0, 90.0, 10.0, 10.0),
     "lt" => Rectangle::new(10.0, 90.0, 10.0, 10.0),
     "minus" => Rectangle::new(20.0, 90.0, 10.0, 10.0),
     "plus" => Rectangle::new(30.0, 90.0, 10.0, 10.0),
+    "stump" => Rectangle::new(40.0, 90.0, 10.0, 10.0),
+    "rubble" => Rectangle::new(50.0, 90.0, 10.0, 10.0),
+    "log" => Rectangle::new(60.0, 90.0, 10.0, 10.0),
+    "branch" => Rectangle::new(70.0, 90.0, 10.0, 10.0),
+    "stone" => Rectangle::new(80.0, 90.0, 10.0, 10.0),
+    "soil" => Rectangle::new(50.0, 90.0, 10.0, 10.0),
+    "sledgehammer" => Rectangle::new(90.0, 90.0, 10.0, 10.0),
     "dead_tree" => Rectangle::new(70.0, 20.0, 20.0, 20.0),
     "dead_pine" => Rectangle::new(70.0, 40.0, 20.0, 20.0),
     "dead_hickory" => Rectangle::new(70.0, 60.0, 20.0, 20.0),
@@ -80,7 +105,7 @@ impl Tileset {
         })
     }
 
-    pub fn get_size(name: &'static str) -> Vec2 {
+    pub fn get_size(name: &str) -> Vec2 {
         REGIONS
             .get(name)
             .map(|r| Vec2::new(r.width, r.height))
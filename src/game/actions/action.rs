@@ -1,8 +1,10 @@
 use super::{
     super::{
         log::{LogCategory, LogEvent},
+        savage::{Attribute, Modifiers},
         Avatar, World,
     },
+    implements::Skip,
     ActionImpl, ActionPossibility, ActionType,
 };
 
@@ -16,6 +18,9 @@ pub struct Action {
 
 impl Action {
     pub fn new(owner: usize, typ: ActionType, world: &World) -> Result<Self, String> {
+        if !world.get_unit(owner).is_alive() {
+            return Err("Dead can't act".to_string());
+        }
         match typ.is_possible(world.get_unit(owner), world) {
             ActionPossibility::Yes(length) => {
                 let finish = world.meta.current_tick + length as u128;
@@ -49,6 +54,44 @@ impl Action {
         }
     }
 
+    /// Shaken unit loses whole action if it fails Spirit roll
+    fn try_unshake(&self, world: &mut World) -> bool {
+        let owner = self.owner(world);
+        if !owner.char_sheet.shaken {
+            return true;
+        }
+        let spirit =
+            owner.roll_attribute(Attribute::Spirit, Modifiers::default(), &mut *world.rng());
+        let pos = owner.pos;
+        let name = owner.name_for_actions();
+        if spirit.is_success() {
+            self.owner_mut(world).char_sheet.shaken = false;
+            world.log().push(
+                LogEvent::new(
+                    format!("{name} shake off the shock"),
+                    pos,
+                    LogCategory::Info,
+                )
+                .with_roll(&spirit),
+            );
+            true
+        } else {
+            self.owner_mut(world).action = Some(Self {
+                typ: Skip {}.into(),
+                ..self.clone()
+            });
+            world.log().push(
+                LogEvent::new(
+                    format!("{name} can't act because of the shock"),
+                    pos,
+                    LogCategory::Warning,
+                )
+                .with_roll(&spirit),
+            );
+            false
+        }
+    }
+
     /// called every tick
     pub fn act(&self, world: &mut World) {
         if let ActionPossibility::No(reason) = self.typ.is_possible(self.owner(world), world) {
@@ -58,6 +101,9 @@ impl Action {
 
         let steps = (self.finish - world.meta.current_tick) as u32;
         if steps == self.length {
+            if !self.try_unshake(world) {
+                return;
+            }
             self.typ.on_start(self, world);
         }
         if steps == 0 {
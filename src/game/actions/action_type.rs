@@ -1,8 +1,12 @@
 use enum_dispatch::enum_dispatch;
+use geometry::Direction;
 
 use super::{
     super::{Avatar, World},
-    implements::{Dig, Drop, Read, Skip, Walk, Wield},
+    implements::{
+        Attack, Bury, Chop, Climb, Dig, Drop, Fill, PutIn, Read, Shoot, Skip, Smash, Swim, TakeOff,
+        TakeOut, Throw, Walk, Wear, Wield,
+    },
     Action, ActionImpl, ActionPossibility,
 };
 
@@ -15,22 +19,54 @@ pub enum ActionType {
     Drop,
     Dig,
     Read,
+    Attack,
+    PutIn,
+    TakeOut,
+    Wear,
+    TakeOff,
+    Swim,
+    Climb,
+    Chop,
+    Smash,
+    Fill,
+    Bury,
+    Shoot,
+    Throw,
+}
+
+impl ActionType {
+    /// Walking, swimming or climbing down, depending on terrain around
+    pub fn movement(dir: Direction, actor: &Avatar, world: &World) -> Self {
+        if Climb::is_on_height(actor, world) {
+            Climb { dir }.into()
+        } else if Swim::is_needed(actor, dir, world) {
+            Swim { dir }.into()
+        } else {
+            Walk { dir }.into()
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use geometry::{Direction, Point};
+    use geometry::{Direction, Point, DIR8};
 
     use super::{
         super::super::{
+            dress::Dress,
             map::{
-                items::helpers::{axe, shovel},
-                terrains::{Boulder, BoulderSize, Dirt},
-                Terrain,
+                items::helpers::{
+                    arrow, axe, backpack, bow, cloak, leather_armor, shovel, sledgehammer, stone,
+                },
+                terrains::{Boulder, BoulderSize, Custom, Dirt, Tree, TreeVariant},
+                Terrain, TerrainInteract, TerrainView,
             },
+            savage::SkillLevel,
             world::tests::{add_npc, prepare_world},
+            Dice, Hand, World,
         },
-        Action, Dig, Drop, Skip, Walk, Wield,
+        Action, ActionType, Attack, Bury, Chop, Climb, Dig, Drop, Fill, PutIn, Shoot, Skip, Smash,
+        Swim, TakeOff, TakeOut, Throw, Walk, Wear, Wield,
     };
 
     #[test]
@@ -128,7 +164,7 @@ mod tests {
         world.map().get_tile_mut(Point::new(1, 0)).items.clear();
         world.map().get_tile_mut(Point::new(1, 0)).items.push(axe());
 
-        assert!(world.player().wield.is_empty());
+        assert!(world.player().hands.is_empty());
         assert_eq!(0, world.meta.current_tick);
 
         world.player_mut().action = Some(
@@ -145,8 +181,7 @@ mod tests {
         world.tick();
 
         assert_eq!(Point::new(0, 0), world.player().pos);
-        assert_eq!(1, world.player().wield.len());
-        let item = world.player().wield.first().unwrap();
+        let item = world.player().hands.main.as_ref().unwrap();
         assert_eq!(item.proto.id, axe().proto.id);
     }
 
@@ -165,14 +200,14 @@ mod tests {
         let mut world = prepare_world();
         world.map().get_tile_mut(Point::new(0, 0)).terrain = Dirt::default().into();
         world.map().get_tile_mut(Point::new(0, 0)).items.clear();
-        world.player_mut().wield.clear();
-        world.player_mut().wield.push(axe());
+        world.player_mut().hands.take_all();
+        world.player_mut().hands.put(Hand::Main, axe());
 
         world.player_mut().action = Some(
             Action::new(
                 0,
                 Drop {
-                    item_id: 0,
+                    hand: Hand::Main,
                     dir: Direction::Here,
                 }
                 .into(),
@@ -183,17 +218,155 @@ mod tests {
         world.tick();
 
         assert_eq!(Point::new(0, 0), world.player().pos);
-        assert_eq!(0, world.player().wield.len());
+        assert!(world.player().hands.is_empty());
         let mut map = world.map();
         assert_eq!(1, map.get_tile(Point::new(0, 0)).items.len());
         let item = map.get_tile(Point::new(0, 0)).items.first().unwrap();
         assert_eq!(item.proto.id, axe().proto.id);
     }
 
+    #[test]
+    fn test_swimming_and_sinking() {
+        let mut world = prepare_world();
+        world.map().get_tile_mut(Point::new(1, 0)).terrain = Custom::new("deep_water", 0).into();
+        world.player_mut().hands.take_all();
+        world.player_mut().hands.put(Hand::Main, axe());
+
+        let east = Direction::East;
+        assert!(Action::new(0, Walk { dir: east }.into(), &world).is_err());
+        let typ = ActionType::movement(east, world.player(), &world);
+        assert!(matches!(typ, ActionType::Swim(..)));
+        world.player_mut().action = Some(Action::new(0, typ, &world).unwrap());
+        world.tick();
+        assert_eq!(Point::new(1, 0), world.player().pos);
+        assert!(world.player().is_alive());
+
+        let drop = Drop {
+            hand: Hand::Main,
+            dir: Direction::Here,
+        };
+        world.player_mut().action = Some(Action::new(0, drop.into(), &world).unwrap());
+        world.tick();
+        assert!(world.player().hands.is_empty());
+        assert!(world.map().get_tile(Point::new(1, 0)).items.is_empty());
+
+        // one can't walk out of deep water
+        assert!(Action::new(0, Walk { dir: east }.into(), &world).is_err());
+        world.player_mut().action =
+            Some(Action::new(0, Swim { dir: east }.into(), &world).unwrap());
+        world.tick();
+        assert_eq!(Point::new(2, 0), world.player().pos);
+        assert!(matches!(
+            ActionType::movement(east, world.player(), &world),
+            ActionType::Walk(..)
+        ));
+    }
+
+    #[test]
+    fn test_climbing() {
+        let mut world = prepare_world();
+        world.map().get_tile_mut(Point::new(1, 0)).terrain =
+            Tree::new(TreeVariant::DeadTree).into();
+        world.player_mut().char_sheet.skills.climbing = SkillLevel::D12;
+
+        let east = Direction::East;
+        assert!(Action::new(0, Walk { dir: east }.into(), &world).is_err());
+        // falling is possible even for the best climber
+        for _ in 0..10 {
+            world.player_mut().action =
+                Some(Action::new(0, Climb { dir: east }.into(), &world).unwrap());
+            world.tick();
+            world.player_mut().char_sheet.wounds = 0;
+            world.player_mut().char_sheet.shaken = false;
+            if world.player().pos == Point::new(1, 0) {
+                break;
+            }
+        }
+        assert_eq!(Point::new(1, 0), world.player().pos);
+        assert!(Climb::is_on_height(world.player(), &world));
+
+        let west = Direction::West;
+        assert!(Action::new(0, Walk { dir: west }.into(), &world).is_err());
+        let typ = ActionType::movement(west, world.player(), &world);
+        assert!(matches!(typ, ActionType::Climb(..)));
+        world.player_mut().action = Some(Action::new(0, typ, &world).unwrap());
+        world.tick();
+        // one gets down even after falling
+        assert_eq!(Point::new(0, 0), world.player().pos);
+    }
+
+    #[test]
+    fn test_putting_in_and_taking_out() {
+        let mut world = prepare_world();
+        world.player_mut().hands.take_all();
+        world.player_mut().wear = Dress::new(vec![backpack()]);
+        world.player_mut().hands.put(Hand::Main, axe());
+
+        let put_in = PutIn {
+            hand: Hand::Main,
+            container_id: 0,
+        };
+        world.player_mut().action = Some(Action::new(0, put_in.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        assert!(world.player().hands.is_empty());
+        assert_eq!(1, world.player().wear.get(0).unwrap().contents().len());
+        assert!(Action::new(0, put_in.into(), &world).is_err());
+
+        let take_out = TakeOut {
+            container_id: 0,
+            item_id: 0,
+        };
+        world.player_mut().action = Some(Action::new(0, take_out.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        assert!(world.player().wear.get(0).unwrap().contents().is_empty());
+        assert_eq!(
+            axe().proto.id,
+            world.player().hands.main.as_ref().unwrap().proto.id
+        );
+        assert!(Action::new(0, take_out.into(), &world).is_err());
+    }
+
+    #[test]
+    fn test_wearing_and_taking_off() {
+        let mut world = prepare_world();
+        world.player_mut().hands.take_all();
+        world.player_mut().wear = Dress::new(vec![cloak()]);
+        world.player_mut().hands.put(Hand::Main, leather_armor());
+        let toughness = world.player().toughness();
+
+        let wear = Wear { hand: Hand::Main };
+        // armor can't be put on over the cloak
+        assert!(Action::new(0, wear.into(), &world).is_err());
+
+        world.player_mut().wear = Dress::default();
+
+        world.player_mut().action = Some(Action::new(0, wear.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        assert!(world.player().hands.main.is_none());
+        assert_eq!(
+            toughness + leather_armor().armor(),
+            world.player().toughness()
+        );
+
+        let take_off = TakeOff { item_id: 0 };
+        world.player_mut().action = Some(Action::new(0, take_off.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        assert!(world.player().wear.is_empty());
+        assert_eq!(toughness, world.player().toughness());
+    }
+
     #[test]
     fn test_digging() {
         let mut world = prepare_world();
-        world.player_mut().wield.clear();
+        world.player_mut().hands.take_all();
         world.map().get_tile_mut(Point::new(1, 0)).terrain = Dirt::default().into();
 
         let typ = Dig {
@@ -201,7 +374,7 @@ mod tests {
         };
         assert!(Action::new(0, typ.into(), &world).is_err());
 
-        world.player_mut().wield.push(shovel());
+        world.player_mut().hands.put(Hand::Main, shovel());
         world.player_mut().action = Some(Action::new(0, typ.into(), &world).unwrap());
         while world.player().action.is_some() {
             world.tick();
@@ -214,6 +387,305 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_filling_and_burying() {
+        let mut world = prepare_world();
+        world.player_mut().hands.take_all();
+        world.player_mut().hands.put(Hand::Main, shovel());
+        let east = Direction::East;
+        let pos = Point::new(1, 0);
+        world.map().get_tile_mut(pos).terrain = Dirt::default().into();
+        assert!(Action::new(0, Fill { dir: east }.into(), &world).is_err());
+        let soil_around = |world: &World| -> Vec<Point> {
+            DIR8.iter()
+                .map(|d| pos + *d)
+                .filter(|p| {
+                    world
+                        .map()
+                        .get_tile(*p)
+                        .items
+                        .iter()
+                        .any(|i| i.proto.id == "soil")
+                })
+                .collect()
+        };
+
+        world.player_mut().action = Some(Action::new(0, Dig { dir: east }.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        // dug out soil is needed to fill the pit back
+        let soil = soil_around(&world);
+        assert_eq!(1, soil.len());
+        assert_ne!(world.player().pos, soil[0]);
+        let item = world.map().get_tile_mut(soil[0]).items.pop().unwrap();
+        assert!(Action::new(0, Fill { dir: east }.into(), &world).is_err());
+        world.map().get_tile_mut(soil[0]).items.push(item);
+
+        world.player_mut().action =
+            Some(Action::new(0, Fill { dir: east }.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        assert!(matches!(
+            world.map().get_tile(pos).terrain,
+            Terrain::Dirt(..)
+        ));
+        assert!(world.map().get_tile(pos).terrain.is_passable());
+        assert!(soil_around(&world).is_empty());
+
+        world.player_mut().action = Some(Action::new(0, Dig { dir: east }.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        world.player_mut().hands.take_all();
+        assert!(Action::new(0, Fill { dir: east }.into(), &world).is_err());
+        world.player_mut().hands.put(Hand::Main, axe());
+        let typ = Bury {
+            hand: Hand::Main,
+            dir: east,
+        };
+        assert!(Action::new(0, typ.into(), &world).is_err());
+
+        // stone is thrown into the pit to fill it
+        world.player_mut().hands.put(Hand::Off, stone());
+        world.player_mut().action = Some(Action::new(0, typ.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        assert!(world.player().hands.is_empty());
+        assert!(world.map().get_tile(pos).items.is_empty());
+
+        world.player_mut().hands.put(Hand::Main, shovel());
+        world.player_mut().action = Some(Action::new(0, Dig { dir: east }.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        let mut map = world.map();
+        let tile = map.get_tile(pos);
+        assert!(matches!(tile.terrain, Terrain::Pit(..)));
+        assert_eq!(1, tile.items.len());
+        assert_eq!("axe", tile.items[0].proto.id);
+    }
+
+    #[test]
+    fn test_chopping() {
+        let mut world = prepare_world();
+        world.player_mut().hands.take_all();
+        world.map().get_tile_mut(Point::new(1, 0)).terrain =
+            Tree::new(TreeVariant::DeadPine).into();
+
+        let typ = Chop {
+            dir: Direction::East,
+        };
+        assert!(Action::new(0, typ.into(), &world).is_err());
+        world.player_mut().hands.put(Hand::Main, axe());
+        let smash = Smash {
+            dir: Direction::East,
+        };
+        assert!(Action::new(0, smash.into(), &world).is_err());
+
+        world.player_mut().char_sheet.attributes.strength = Dice::D4;
+        let weak = Action::new(0, typ.into(), &world).unwrap();
+        world.player_mut().char_sheet.attributes.strength = Dice::D12;
+        let strong = Action::new(0, typ.into(), &world).unwrap();
+        assert!(strong.length < weak.length);
+
+        // interrupted work is not lost
+        let strong_length = strong.length;
+        world.player_mut().action = Some(strong);
+        world.tick();
+        world.player_mut().action = None;
+        let hit_points = world.map().get_tile(Point::new(1, 0)).terrain.hit_points();
+        assert!(hit_points > 0);
+        let action = Action::new(0, typ.into(), &world).unwrap();
+        assert!(action.length < strong_length);
+
+        world.player_mut().action = Some(action);
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        let mut map = world.map();
+        assert_eq!("stump", map.get_tile(Point::new(1, 0)).terrain.name());
+        let logs = (0..=2)
+            .flat_map(|x| (-1..=1).map(move |y| Point::new(x, y)))
+            .flat_map(|pos| map.get_tile(pos).items.clone())
+            .filter(|i| i.proto.id == "log")
+            .count();
+        assert_eq!(2, logs);
+    }
+
+    #[test]
+    fn test_smashing() {
+        let mut world = prepare_world();
+        world.player_mut().hands.take_all();
+        world.player_mut().hands.put(Hand::Main, sledgehammer());
+        world.map().get_tile_mut(Point::new(1, 0)).terrain =
+            Boulder::new(BoulderSize::Middle).into();
+
+        let typ = Smash {
+            dir: Direction::East,
+        };
+        world.player_mut().action = Some(Action::new(0, typ.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+
+        let mut map = world.map();
+        let tile = map.get_tile(Point::new(1, 0));
+        assert_eq!("rubble", tile.terrain.name());
+        assert!(tile.terrain.is_passable());
+        assert_eq!(
+            4,
+            tile.items.iter().filter(|i| i.proto.id == "stone").count()
+        );
+    }
+
+    #[test]
+    fn test_attacking() {
+        let mut world = prepare_world();
+        let npc = add_npc(&mut world, Point::new(1, 0));
+        world.player_mut().hands.take_all();
+        world.player_mut().hands.put(Hand::Main, axe());
+
+        let typ = Attack {
+            dir: Direction::East,
+        };
+        assert!(Action::new(
+            0,
+            Attack {
+                dir: Direction::West
+            }
+            .into(),
+            &world
+        )
+        .is_err());
+
+        let mut attacks = 0;
+        while world.get_unit(npc).is_alive() {
+            assert!(attacks < 100, "npc is still alive after {attacks} attacks");
+            world.player_mut().action = Some(Action::new(0, typ.into(), &world).unwrap());
+            while world.player().action.is_some() {
+                world.tick();
+            }
+            attacks += 1;
+        }
+
+        assert_eq!(3, world.get_unit(npc).char_sheet.wounds);
+        assert!(Action::new(npc, Skip {}.into(), &world).is_err());
+        assert!(Action::new(0, typ.into(), &world).is_err());
+        let mut map = world.map();
+        let tile = map.get_tile(Point::new(1, 0));
+        assert!(tile.units.is_empty());
+        assert!(tile.items.iter().any(|i| i.proto.id == "corpse"));
+    }
+
+    #[test]
+    fn test_shooting() {
+        let mut world = prepare_world();
+        let npc = add_npc(&mut world, Point::new(5, 0));
+        world.player_mut().hands.take_all();
+        world.player_mut().wear = Dress::new(vec![backpack()]);
+        world.player_mut().char_sheet.skills.shooting = SkillLevel::D12;
+        let typ = Shoot {
+            target: Point::new(5, 0),
+        };
+        assert!(Action::new(0, typ.into(), &world).is_err());
+        // launchers without an ammo tag can't shoot anything
+        let mut launcher = bow();
+        launcher.proto.shooting.as_mut().unwrap().ammo = None;
+        world.player_mut().hands.put(Hand::Main, launcher);
+        assert!(Action::new(0, typ.into(), &world).is_err());
+        world.player_mut().hands.take_all();
+        world.player_mut().hands.put(Hand::Main, bow());
+        assert!(Action::new(0, typ.into(), &world).is_err());
+        for _ in 0..30 {
+            world
+                .player_mut()
+                .wear
+                .get_mut(0)
+                .unwrap()
+                .contents_mut()
+                .unwrap()
+                .push(arrow());
+        }
+        assert!(Action::new(
+            0,
+            Shoot {
+                target: Point::new(49, 0)
+            }
+            .into(),
+            &world
+        )
+        .is_err());
+        world.map().get_tile_mut(Point::new(3, 0)).terrain = Boulder::new(BoulderSize::Huge).into();
+        assert!(Action::new(0, typ.into(), &world).is_err());
+        world.map().get_tile_mut(Point::new(3, 0)).terrain = Dirt::default().into();
+
+        let mut shots = 0;
+        while world.get_unit(npc).is_alive() {
+            assert!(shots < 30, "npc is still alive after {shots} shots");
+            world.player_mut().action = Some(Action::new(0, typ.into(), &world).unwrap());
+            while world.player().action.is_some() {
+                world.tick();
+            }
+            shots += 1;
+        }
+
+        assert_eq!(
+            30 - shots,
+            world.player().wear.get(0).unwrap().contents().len()
+        );
+        let arrows: usize = (-10..=10)
+            .map(|x| {
+                world
+                    .map()
+                    .get_tile(Point::new(x, 0))
+                    .items
+                    .iter()
+                    .filter(|i| i.proto.id == "arrow")
+                    .count()
+            })
+            .sum();
+        assert!(arrows > 0);
+    }
+
+    #[test]
+    fn test_throwing() {
+        let mut world = prepare_world();
+        world.player_mut().hands.take_all();
+        world.player_mut().hands.put(Hand::Main, backpack());
+        let typ = Throw {
+            hand: Hand::Main,
+            target: Point::new(3, 2),
+        };
+        assert!(Action::new(0, typ.into(), &world).is_err());
+        world.player_mut().hands.take_all();
+        world.player_mut().hands.put(Hand::Main, stone());
+        assert!(Action::new(
+            0,
+            Throw {
+                hand: Hand::Main,
+                target: Point::new(0, 13),
+            }
+            .into(),
+            &world
+        )
+        .is_err());
+
+        world.player_mut().action = Some(Action::new(0, typ.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        assert!(world.player().hands.is_empty());
+        assert!(world
+            .map()
+            .get_tile(Point::new(3, 2))
+            .items
+            .iter()
+            .any(|i| i.proto.id == "stone"));
+    }
+
     #[test]
     fn test_reading() {
         // TODO
@@ -0,0 +1,168 @@
+use geometry::Direction;
+
+use super::super::{
+    super::{
+        experience::Reward,
+        log::{LogCategory, LogEvent},
+        savage::{HitResult, Modifiers, Skill},
+        Avatar, Dice, World,
+    },
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Attack {
+    pub dir: Direction,
+}
+
+impl Attack {
+    /// Units share a tile in any order, so the one with the lowest id is attacked
+    fn target(self, actor: &Avatar, world: &World) -> Option<usize> {
+        let pos = actor.pos + self.dir;
+        let unit_id = world
+            .map()
+            .get_tile(pos)
+            .units
+            .iter()
+            .copied()
+            .filter(|&id| world.get_unit(id).is_alive())
+            .min();
+        unit_id
+    }
+}
+
+impl ActionImpl for Attack {
+    fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
+        if self.dir.is_here() {
+            return No("You can't attack yourself".to_string());
+        }
+        if self.target(actor, world).is_none() {
+            return No("There is no one to attack".to_string());
+        }
+        Yes(actor
+            .hands
+            .weapon()
+            .map_or(10.0, |(_, item)| item.attack_time())
+            .round() as u32)
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let attacker = action.owner(world);
+        let Some(target) = self.target(attacker, world) else {
+            return;
+        };
+        let defender = world.get_unit(target);
+        let pos = defender.pos;
+        let attacker_name = attacker.name_for_actions();
+        let defender_name = defender_name(defender);
+        let parry = defender.char_sheet.parry();
+        let penalty = attacker
+            .hands
+            .weapon()
+            .map_or(0, |(hand, _)| attacker.hand_penalty(hand));
+
+        let modifiers = Modifiers::default()
+            .with("off hand", penalty)
+            .against(i16::from(parry));
+        let roll = world.roll_skill(action.owner, Skill::Fighting, modifiers);
+        if !roll.is_success() {
+            if !world.offer_reroll(action, &roll) {
+                world.log().push(
+                    LogEvent::new(
+                        format!("{attacker_name} attack {defender_name} but miss"),
+                        pos,
+                        LogCategory::Info,
+                    )
+                    .with_roll(&roll),
+                );
+            }
+            return;
+        }
+        let attacker = action.owner(world);
+        let weapon = attacker.hands.weapon();
+        let mut rng = world.rng();
+        let mut damage = attacker.char_sheet.attributes.strength.roll_wild(&mut *rng);
+        if let Some(dice) = weapon.and_then(|(_, item)| item.melee_damage()) {
+            damage = damage.saturating_add(dice.roll_wild(&mut *rng));
+        }
+        // bonus damage for a raise
+        if roll.raises() > 0 {
+            damage = damage.saturating_add(Dice::D6.roll_wild(&mut *rng));
+        }
+        drop(rng);
+
+        hit(
+            world,
+            &attacker_name,
+            target,
+            damage,
+            vec![roll.breakdown()],
+        );
+        reward_kill(world, action.owner, target);
+    }
+}
+
+pub fn defender_name(defender: &Avatar) -> String {
+    if defender.is_player() {
+        "you".to_string()
+    } else {
+        defender.personality.mind.name.clone()
+    }
+}
+
+/// Killing a unit is worth experience for the player
+pub fn reward_kill(world: &mut World, attacker: usize, target: usize) {
+    if attacker == 0 && !world.get_unit(target).is_alive() {
+        world.award_experience(Reward::Kill);
+    }
+}
+
+/// Applies damage to the unit and logs the result, used by melee and ranged attacks,
+/// `details` are breakdowns of the rolls shown with the log message
+pub fn hit(
+    world: &mut World,
+    attacker_name: &str,
+    target: usize,
+    damage: u8,
+    details: Vec<String>,
+) {
+    if world.offer_soak(target, attacker_name, damage, &details) {
+        return;
+    }
+    let defender = world.get_unit(target);
+    let pos = defender.pos;
+    let defender_name = defender_name(defender);
+    let armor = defender.armor();
+    let result = world
+        .get_unit_mut(target)
+        .char_sheet
+        .apply_damage(damage, armor);
+    let (msg, category) = match result {
+        HitResult::Nothing => (
+            format!("{attacker_name} hit {defender_name} but it has no effect"),
+            LogCategory::Info,
+        ),
+        HitResult::Shaken => (
+            format!("{attacker_name} hit {defender_name}, {defender_name} is shaken"),
+            LogCategory::Warning,
+        ),
+        HitResult::Wounded(wounds) => (
+            format!(
+                "{attacker_name} hit {defender_name}, causing {wounds} wound{}",
+                if wounds > 1 { "s" } else { "" }
+            ),
+            LogCategory::Danger,
+        ),
+        HitResult::Incapacitated => (
+            format!("{attacker_name} hit {defender_name} mortally"),
+            LogCategory::Danger,
+        ),
+    };
+    world
+        .log()
+        .push(LogEvent::new(msg, pos, category).with_details(details));
+    if result == HitResult::Incapacitated {
+        world.kill_unit(target);
+    }
+}
@@ -0,0 +1,106 @@
+//! Common logic of [`super::Chop`] and [`super::Smash`]
+
+use geometry::{Direction, DIR8};
+use rand::seq::SliceRandom;
+
+use super::super::{
+    super::{
+        log::{LogCategory, LogEvent},
+        map::{TerrainInteract, TerrainView},
+        Avatar, ItemQuality, World,
+    },
+    Action,
+    ActionPossibility::{self, No, Yes},
+};
+
+/// Ticks to knock off one hit point with d6 in Strength
+const TICKS_PER_HIT_POINT: f32 = 10.0;
+
+/// Stronger units break terrain faster
+pub fn ticks_per_hit_point(actor: &Avatar) -> u32 {
+    let k_strength = 6.0 / f32::from(actor.char_sheet.attributes.strength.value());
+    u32::max(1, f32::round(TICKS_PER_HIT_POINT * k_strength) as u32)
+}
+
+pub fn is_possible(
+    actor: &Avatar,
+    world: &World,
+    dir: Direction,
+    quality: ItemQuality,
+    verb: &str,
+    tool: &str,
+) -> ActionPossibility {
+    let mut map = world.map();
+    let tile = map.get_tile(actor.pos + dir);
+    if tile.terrain.breaking_quality() != Some(quality) {
+        return No(format!("You can't {verb} the {}", tile.terrain.name()));
+    }
+    if !actor
+        .hands
+        .items()
+        .any(|i| i.qualities().contains(&quality))
+    {
+        return No(format!(
+            "You need {tool} to {verb} the {}",
+            tile.terrain.name()
+        ));
+    }
+
+    Yes(u32::from(tile.terrain.hit_points()) * ticks_per_hit_point(actor))
+}
+
+pub fn on_start(action: &Action, world: &mut World, dir: Direction, verb: &str) {
+    let owner = action.owner(world);
+    let pos = owner.pos + dir;
+    let msg = format!(
+        "{} started {verb} the {}",
+        owner.name_for_actions(),
+        world.map().get_tile(pos).terrain.name()
+    );
+    world
+        .log()
+        .push(LogEvent::new(msg, owner.pos, LogCategory::Info));
+}
+
+/// Progress is kept in the terrain, so interrupted work can be continued
+pub fn on_step(action: &Action, world: &mut World, dir: Direction) {
+    let owner = action.owner(world);
+    let pos = owner.pos + dir;
+    let ticks_per_hit_point = ticks_per_hit_point(owner);
+    let passed = action.length - (action.finish - world.meta.current_tick) as u32;
+    if passed > 0 && passed % ticks_per_hit_point == 0 {
+        world.map().get_tile_mut(pos).terrain.damage(1);
+    }
+}
+
+pub fn on_finish(action: &Action, world: &mut World, dir: Direction, verb: &str) {
+    let owner_pos = action.owner(world).pos;
+    let pos = owner_pos + dir;
+    let name = world.map().get_tile(pos).terrain.name().to_string();
+    let items = world.map().get_tile_mut(pos).break_terrain();
+    if world.map().get_tile(pos).terrain.can_stock_items() {
+        world.map().get_tile_mut(pos).items.extend(items);
+    } else {
+        let mut rng = world.rng();
+        let places: Vec<Direction> = DIR8
+            .iter()
+            .copied()
+            .filter(|d| {
+                (pos + *d != owner_pos) && world.map().get_tile(pos + *d).terrain.can_stock_items()
+            })
+            .collect();
+        for item in items {
+            let delta = places.choose(&mut *rng).copied().unwrap_or(Direction::Here);
+            world.map().get_tile_mut(pos + delta).items.push(item);
+        }
+    }
+    world.calc_fov();
+    world.log().push(LogEvent::new(
+        format!(
+            "{} {verb} the {name}",
+            action.owner(world).name_for_actions()
+        ),
+        pos,
+        LogCategory::Info,
+    ));
+}
@@ -0,0 +1,38 @@
+use geometry::Direction;
+
+use super::{
+    super::{
+        super::{Avatar, Hand, World},
+        Action, ActionImpl,
+        ActionPossibility::{self, No, Yes},
+    },
+    Fill,
+};
+
+/// Puts held item into a pit and fills it
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Bury {
+    pub hand: Hand,
+    pub dir: Direction,
+}
+
+impl ActionImpl for Bury {
+    fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
+        let Some(item) = actor.hands.get(self.hand) else {
+            return No("You have nothing to bury".to_string());
+        };
+        match Fill::possibility(actor, world, self.dir, Some(self.hand)) {
+            Yes(length) => Yes(length + item.drop_time().round() as u32),
+            No(reason) => No(reason),
+        }
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let Some(item) = action.owner_mut(world).hands.take(self.hand) else {
+            return;
+        };
+        let pos = action.owner(world).pos + self.dir;
+        world.map().get_tile_mut(pos).items.push(item);
+        Fill { dir: self.dir }.on_finish(action, world);
+    }
+}
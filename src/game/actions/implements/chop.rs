@@ -0,0 +1,33 @@
+use geometry::Direction;
+
+use super::{
+    super::{
+        super::{Avatar, ItemQuality, World},
+        Action, ActionImpl, ActionPossibility,
+    },
+    breaking,
+};
+
+/// Chop down a tree to logs and branches
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Chop {
+    pub dir: Direction,
+}
+
+impl ActionImpl for Chop {
+    fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
+        breaking::is_possible(actor, world, self.dir, ItemQuality::Butch, "chop", "an axe")
+    }
+
+    fn on_start(&self, action: &Action, world: &mut World) {
+        breaking::on_start(action, world, self.dir, "chopping");
+    }
+
+    fn on_step(&self, action: &Action, world: &mut World) {
+        breaking::on_step(action, world, self.dir);
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        breaking::on_finish(action, world, self.dir, "chopped down");
+    }
+}
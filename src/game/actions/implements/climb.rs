@@ -0,0 +1,118 @@
+use geometry::Direction;
+
+use super::super::{
+    super::{
+        log::{LogCategory, LogEvent},
+        map::{TerrainInteract, TerrainView},
+        savage::{Modifiers, Skill, SkillLevel},
+        Avatar, Dice, World,
+    },
+    implements::hit,
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+/// Ticks to climb up with d4 in Climbing
+const CLIMB_UP_TIME: f32 = 50.0;
+/// Ticks to climb down with d4 in Climbing
+const CLIMB_DOWN_TIME: f32 = 30.0;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Climb {
+    pub dir: Direction,
+}
+
+impl Climb {
+    /// Unit stands on a boulder or a tree
+    pub fn is_on_height(actor: &Avatar, world: &World) -> bool {
+        world.map().get_tile(actor.pos).terrain.is_climbable()
+    }
+}
+
+impl ActionImpl for Climb {
+    fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
+        if self.dir.is_here() {
+            return No("You can't climb here".to_string());
+        }
+        let on_height = Self::is_on_height(actor, world);
+        let mut map = world.map();
+        let tile = map.get_tile(actor.pos + self.dir);
+        if on_height && !tile.terrain.is_passable() {
+            return No(format!(
+                "You can't climb down to the {}",
+                tile.terrain.name()
+            ));
+        }
+        if !on_height && !tile.terrain.is_climbable() {
+            return No(format!("You can't climb the {}", tile.terrain.name()));
+        }
+        if let Some(unit_id) = tile.units.iter().copied().next() {
+            let unit = world.get_unit(unit_id);
+            return No(format!("{} is on the way", unit.name_for_actions()));
+        }
+
+        let k_skill = match actor.char_sheet.skills.climbing {
+            SkillLevel::D4_2 => 1.5,
+            skill => 4.0 / f32::from(Dice::from(skill).value()),
+        };
+        let climb_time = if on_height {
+            CLIMB_DOWN_TIME
+        } else {
+            CLIMB_UP_TIME
+        };
+        Yes(f32::round(climb_time * k_skill) as u32)
+    }
+
+    /// Failed Climbing roll means a fall, climber stays on the ground and takes damage
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let unit = action.owner(world);
+        let name = unit.name_for_actions();
+        let on_height = Self::is_on_height(unit, world);
+        let terrain = if on_height {
+            world.map().get_tile(unit.pos).terrain.name().to_string()
+        } else {
+            world
+                .map()
+                .get_tile(unit.pos + self.dir)
+                .terrain
+                .name()
+                .to_string()
+        };
+        let climbing = world.roll_skill(action.owner, Skill::Climbing, Modifiers::default());
+        if !climbing.is_success() && world.offer_reroll(action, &climbing) {
+            return;
+        }
+        if climbing.is_success() || on_height {
+            world.move_avatar(action.owner, self.dir);
+        }
+        let pos = action.owner(world).pos;
+        if climbing.is_success() {
+            if action.owner == 0 {
+                let msg = if on_height {
+                    format!("You climb down from the {terrain}")
+                } else {
+                    format!("You climb the {terrain}")
+                };
+                world
+                    .log()
+                    .push(LogEvent::new(msg, pos, LogCategory::Info).with_roll(&climbing));
+            }
+            return;
+        }
+
+        let damage = {
+            let mut rng = world.rng();
+            Dice::D6.roll(&mut *rng) + Dice::D6.roll(&mut *rng)
+        };
+        world.log().push(
+            LogEvent::new(
+                format!("{name} fall from the {terrain}"),
+                pos,
+                LogCategory::Warning,
+            )
+            .with_roll(&climbing),
+        );
+        // the same way as attacks, so the player can soak it and a fatal fall kills
+        hit(world, "The fall", action.owner, damage, Vec::new());
+    }
+}
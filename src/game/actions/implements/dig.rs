@@ -29,8 +29,8 @@ impl ActionImpl for Dig {
             };
         }
         if !actor
-            .wield
-            .iter()
+            .hands
+            .items()
             .any(|i| i.qualities().contains(&ItemQuality::Dig))
         {
             return No("You need a shovel to dig!".to_string());
@@ -52,17 +52,18 @@ impl ActionImpl for Dig {
         let pos = action.owner(world).pos + self.dir;
         let items = world.map().get_tile_mut(pos).dig();
         if !items.is_empty() {
-            let mut rng = rand::thread_rng();
+            let mut rng = world.rng();
             let places: Vec<Direction> = DIR8
                 .iter()
                 .copied()
                 .filter(|d| {
                     (pos + *d != action.owner(world).pos)
-                        && world.map().get_tile(pos + *d).terrain.is_passable()
+                        && world.map().get_tile(pos + *d).terrain.can_stock_items()
                 })
                 .collect();
             for item in items {
-                let delta = places.choose(&mut rng).copied().unwrap();
+                // surrounded by walls, items stay in the hole
+                let delta = places.choose(&mut *rng).copied().unwrap_or(Direction::Here);
                 world.map().get_tile_mut(pos + delta).items.push(item);
             }
         }
@@ -4,7 +4,7 @@ use super::super::{
     super::{
         log::{LogCategory, LogEvent},
         map::{TerrainInteract, TerrainView},
-        Avatar, World,
+        Avatar, Hand, World,
     },
     Action, ActionImpl,
     ActionPossibility::{self, No, Yes},
@@ -12,45 +12,46 @@ use super::super::{
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
 pub struct Drop {
-    pub item_id: usize,
+    pub hand: Hand,
     pub dir: Direction,
 }
 
 impl ActionImpl for Drop {
     fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
-        if actor.wield.is_empty() {
+        let Some(item) = actor.hands.get(self.hand) else {
             return No("You have nothing to drop".to_string());
-        }
+        };
         let pos = actor.pos + self.dir;
         let mut map = world.map();
         let tile = map.get_tile(pos);
-        if !tile.terrain.can_stock_items() {
+        if !tile.terrain.can_stock_items() && !tile.sinks_items() {
             return No(format!("You can't put items on {}", tile.terrain.name()));
         }
 
-        if let Some(item) = actor.wield.get(self.item_id) {
-            let k = if matches!(self.dir, Direction::Here) {
-                1.0
-            } else {
-                1.5
-            };
-            Yes((item.drop_time() * k).round() as u32)
+        let k = if matches!(self.dir, Direction::Here) {
+            1.0
         } else {
-            No("Item doesn't exists".to_string())
-        }
+            1.5
+        };
+        Yes((item.drop_time() * k).round() as u32)
     }
 
     fn on_finish(&self, action: &Action, world: &mut World) {
-        let owner = action.owner_mut(world);
-        let item = owner.wield.remove(self.item_id);
+        let Some(item) = action.owner_mut(world).hands.take(self.hand) else {
+            return;
+        };
         let owner = action.owner(world);
         let pos = owner.pos + self.dir;
         let name = item.name().to_string();
-        world.map().get_tile_mut(pos).items.push(item);
-        world.log().push(LogEvent::new(
-            format!("{} dropped the {name}", owner.name_for_actions()),
-            pos,
-            LogCategory::Info,
-        ));
+        let mut map = world.map();
+        let tile = map.get_tile_mut(pos);
+        let msg = if tile.sinks_items() {
+            format!("The {name} sinks")
+        } else {
+            tile.items.push(item);
+            format!("{} dropped the {name}", owner.name_for_actions())
+        };
+        drop(map);
+        world.log().push(LogEvent::new(msg, pos, LogCategory::Info));
     }
 }
@@ -0,0 +1,126 @@
+use geometry::{Direction, Point, DIR8};
+
+use super::super::{
+    super::{
+        log::{LogCategory, LogEvent},
+        map::{Terrain, TerrainView},
+        Avatar, Hand, ItemQuality, ItemTag, World,
+    },
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+/// Ticks to fill a pit with a shovel
+const FILL_TIME: u32 = 300;
+/// Ticks to fill a pit by throwing stones into it
+const FILL_BY_HAND_TIME: u32 = 500;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FillMaterial {
+    /// Filler lying on this tile is shoveled into the pit with a `Dig` tool
+    Shoveled(Point),
+    /// Item from this hand is thrown into the pit
+    Filler(Hand),
+}
+
+/// Turns pit back to dirt, items lying in the pit are buried
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Fill {
+    pub dir: Direction,
+}
+
+impl Fill {
+    /// What unit can fill the `pit` with, item in the `except` hand is ignored.
+    /// Soil dug out of the pit lies around it, so it is shoveled back from there.
+    pub fn material(
+        actor: &Avatar,
+        world: &World,
+        pit: Point,
+        except: Option<Hand>,
+    ) -> Option<FillMaterial> {
+        let items = || actor.hands.iter().filter(|(hand, _)| Some(*hand) != except);
+        if items().any(|(_, i)| i.qualities().contains(&ItemQuality::Dig)) {
+            let mut map = world.map();
+            let shoveled = [Direction::Here]
+                .iter()
+                .chain(DIR8.iter())
+                .map(|d| pit + *d)
+                .find(|&pos| {
+                    map.get_tile(pos)
+                        .items
+                        .iter()
+                        .any(|i| i.tags().contains(&ItemTag::Filler))
+                });
+            if let Some(pos) = shoveled {
+                return Some(FillMaterial::Shoveled(pos));
+            }
+        }
+        items()
+            .find(|(_, i)| i.tags().contains(&ItemTag::Filler))
+            .map(|(hand, _)| FillMaterial::Filler(hand))
+    }
+
+    pub fn possibility(
+        actor: &Avatar,
+        world: &World,
+        dir: Direction,
+        except: Option<Hand>,
+    ) -> ActionPossibility {
+        let pos = actor.pos + dir;
+        let mut map = world.map();
+        let tile = map.get_tile(pos);
+        if !matches!(tile.terrain, Terrain::Pit(..)) {
+            return No(format!("You can't fill the {}", tile.terrain.name()));
+        }
+        drop(map);
+
+        match Self::material(actor, world, pos, except) {
+            Some(FillMaterial::Shoveled(..)) => Yes(FILL_TIME),
+            Some(FillMaterial::Filler(..)) => Yes(FILL_BY_HAND_TIME),
+            None => No("You need some soil or stones to fill the pit".to_string()),
+        }
+    }
+}
+
+impl ActionImpl for Fill {
+    fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
+        Self::possibility(actor, world, self.dir, None)
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = action.owner(world);
+        let pos = owner.pos + self.dir;
+        match Self::material(owner, world, pos, None) {
+            Some(FillMaterial::Shoveled(from)) => {
+                let mut map = world.map();
+                let items = &mut map.get_tile_mut(from).items;
+                if let Some(i) = items
+                    .iter()
+                    .rposition(|i| i.tags().contains(&ItemTag::Filler))
+                {
+                    items.remove(i);
+                }
+            }
+            Some(FillMaterial::Filler(hand)) => {
+                action.owner_mut(world).hands.take(hand);
+            }
+            None => return,
+        }
+        let buried: Vec<String> = world
+            .map()
+            .get_tile(pos)
+            .items
+            .iter()
+            .map(|i| i.name().to_string())
+            .collect();
+        world.map().get_tile_mut(pos).fill();
+
+        let name = action.owner(world).name_for_actions();
+        let msg = if buried.is_empty() {
+            format!("{name} filled the pit")
+        } else {
+            format!("{name} buried the {}", buried.join(", the "))
+        };
+        world.log().push(LogEvent::new(msg, pos, LogCategory::Info));
+    }
+}
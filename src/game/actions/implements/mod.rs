@@ -1,13 +1,41 @@
+pub use attack::{hit, Attack};
+pub use bury::Bury;
+pub use chop::Chop;
+pub use climb::Climb;
 pub use dig::Dig;
 pub use drop::Drop;
+pub use fill::Fill;
+pub use put_in::PutIn;
 pub use read::Read;
+pub use shoot::Shoot;
 pub use skip::Skip;
+pub use smash::Smash;
+pub use swim::Swim;
+pub use take_off::TakeOff;
+pub use take_out::TakeOut;
+pub use throw::Throw;
 pub use walk::Walk;
+pub use wear::Wear;
 pub use wield::Wield;
 
+mod attack;
+mod breaking;
+mod bury;
+mod chop;
+mod climb;
 mod dig;
 mod drop;
+mod fill;
+mod projectile;
+mod put_in;
 mod read;
+mod shoot;
 mod skip;
+mod smash;
+mod swim;
+mod take_off;
+mod take_out;
+mod throw;
 mod walk;
+mod wear;
 mod wield;
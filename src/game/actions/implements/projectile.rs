@@ -0,0 +1,212 @@
+//! Common logic of [`super::Shoot`] and [`super::Throw`]
+
+use geometry::Point;
+
+use super::{
+    super::{
+        super::{
+            game_data::RangedPrototype,
+            log::{LogCategory, LogEvent},
+            map::{line, line_of_sight, TerrainInteract, TerrainView},
+            savage::{Modifiers, Skill, TraitRoll},
+            Avatar, Dice, Item, World,
+        },
+        Action,
+        ActionPossibility::{self, No, Yes},
+    },
+    attack::{defender_name, hit, reward_kill},
+};
+
+/// Target hides behind a boulder
+const COVER_PENALTY: i8 = -4;
+/// Someone stands right in front of the target
+const UNIT_IN_THE_WAY_PENALTY: i8 = -2;
+
+/// Things deciding if the projectile hits
+pub struct Missile {
+    pub ranged: RangedPrototype,
+    pub skill: Skill,
+    /// Penalty of the hand
+    pub penalty: i8,
+}
+
+/// Result of the roll against the unit on the target tile
+#[derive(Clone)]
+pub enum Aim {
+    /// There is nobody on the target tile
+    Tile,
+    Miss(usize, TraitRoll),
+    Hit(usize, TraitRoll),
+}
+
+pub fn is_possible(
+    actor: &Avatar,
+    world: &World,
+    target: Point,
+    ranged: RangedPrototype,
+    length: u32,
+) -> ActionPossibility {
+    if target == actor.pos {
+        return No("You can't aim at yourself".to_string());
+    }
+    if ranged
+        .range_penalty(actor.pos.square_distance(target))
+        .is_none()
+    {
+        return No("The target is too far".to_string());
+    }
+    if !line_of_sight(actor.pos, target, &world.map()) {
+        return No("There is no clear line of fire".to_string());
+    }
+
+    Yes(length)
+}
+
+/// Obstacle right in front of the target makes it harder to hit
+fn cover_penalty(path: &[Point], world: &World) -> i8 {
+    if path.len() < 3 {
+        return 0;
+    }
+    let mut map = world.map();
+    let tile = map.get_tile(path[path.len() - 2]);
+    if tile.terrain.is_cover() {
+        COVER_PENALTY
+    } else if tile.units.is_empty() {
+        0
+    } else {
+        UNIT_IN_THE_WAY_PENALTY
+    }
+}
+
+/// Missed projectiles fly further up to the maximum range
+fn beyond(from: Point, target: Point, max_range: u8) -> Point {
+    let distance = (from.square_distance(target) as f32).sqrt();
+    let k = f32::from(max_range) / distance;
+    Point::new(
+        from.x + ((target.x - from.x) as f32 * k).round() as i32,
+        from.y + ((target.y - from.y) as f32 * k).round() as i32,
+    )
+}
+
+/// Projectile stops in front of the first opaque tile
+fn landing(path: &[Point], world: &World) -> Point {
+    let mut map = world.map();
+    let reached = path
+        .iter()
+        .skip(1)
+        .take_while(|&&p| map.get_tile(p).terrain.is_transparent())
+        .count();
+    let landed = &path[..=reached];
+    landed
+        .iter()
+        .rev()
+        .copied()
+        .find(|&p| {
+            let tile = map.get_tile(p);
+            tile.terrain.can_stock_items() || tile.sinks_items()
+        })
+        .unwrap_or(path[0])
+}
+
+/// Rolls before the projectile leaves the hand,
+/// `None` means the action waits for the player's decision on a reroll
+pub fn aim(action: &Action, world: &mut World, missile: &Missile, target: Point) -> Option<Aim> {
+    let from = action.owner(world).pos;
+    let target_unit = world
+        .map()
+        .get_tile(target)
+        .units
+        .iter()
+        .copied()
+        .find(|&i| world.get_unit(i).is_alive());
+    let Some(unit_id) = target_unit else {
+        return Some(Aim::Tile);
+    };
+    let modifiers = Modifiers::default()
+        .with("off hand", missile.penalty)
+        .with(
+            "range",
+            missile
+                .ranged
+                .range_penalty(from.square_distance(target))
+                .unwrap_or(0),
+        )
+        .with("cover", cover_penalty(&line(from, target), world));
+    let roll = world.roll_skill(action.owner, missile.skill, modifiers);
+    if roll.is_success() {
+        Some(Aim::Hit(unit_id, roll))
+    } else if world.offer_reroll(action, &roll) {
+        None
+    } else {
+        Some(Aim::Miss(unit_id, roll))
+    }
+}
+
+pub fn fly(
+    action: &Action,
+    world: &mut World,
+    item: Item,
+    ranged: RangedPrototype,
+    aim: Aim,
+    target: Point,
+    verb: &str,
+) {
+    let owner = action.owner(world);
+    let from = owner.pos;
+    let attacker_name = owner.name_for_actions();
+
+    let mut end = target;
+    match aim {
+        Aim::Hit(unit_id, roll) => {
+            let mut rng = world.rng();
+            let mut damage = ranged.damage.roll_wild(&mut *rng);
+            if ranged.strength {
+                damage = damage
+                    .saturating_add(owner.char_sheet.attributes.strength.roll_wild(&mut *rng));
+            }
+            // bonus damage for a raise
+            if roll.raises() > 0 {
+                damage = damage.saturating_add(Dice::D6.roll_wild(&mut *rng));
+            }
+            drop(rng);
+            hit(
+                world,
+                &attacker_name,
+                unit_id,
+                damage,
+                vec![roll.breakdown()],
+            );
+            reward_kill(world, action.owner, unit_id);
+        }
+        Aim::Miss(unit_id, roll) => {
+            let defender_name = defender_name(world.get_unit(unit_id));
+            world.log().push(
+                LogEvent::new(
+                    format!("{attacker_name} {verb} at {defender_name} but miss"),
+                    target,
+                    LogCategory::Info,
+                )
+                .with_roll(&roll),
+            );
+            end = beyond(from, target, ranged.max_range());
+        }
+        Aim::Tile => {
+            world.log().push(LogEvent::new(
+                format!("{attacker_name} {verb} the {}", item.name()),
+                from,
+                LogCategory::Info,
+            ));
+        }
+    }
+
+    let pos = landing(&line(from, end), world);
+    let mut map = world.map();
+    let tile = map.get_tile_mut(pos);
+    if tile.sinks_items() {
+        let msg = format!("The {} sinks", item.name());
+        drop(map);
+        world.log().push(LogEvent::new(msg, pos, LogCategory::Info));
+    } else {
+        tile.items.push(item);
+    }
+}
@@ -0,0 +1,54 @@
+use super::super::{
+    super::{
+        log::{LogCategory, LogEvent},
+        Avatar, Hand, World,
+    },
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct PutIn {
+    pub hand: Hand,
+    /// Index of worn container
+    pub container_id: usize,
+}
+
+impl ActionImpl for PutIn {
+    fn is_possible(&self, actor: &Avatar, _world: &World) -> ActionPossibility {
+        let Some(item) = actor.hands.get(self.hand) else {
+            return No("You have nothing in this hand".to_string());
+        };
+        let Some(container) = actor.wear.get(self.container_id) else {
+            return No("You don't wear this".to_string());
+        };
+        match container.can_hold(item) {
+            Ok(..) => Yes(item.wield_time().round() as u32),
+            Err(reason) => No(reason),
+        }
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = action.owner_mut(world);
+        let Some(item) = owner.hands.take(self.hand) else {
+            return;
+        };
+        let item_name = item.name().to_string();
+        let Some(container) = owner.wear.get_mut(self.container_id) else {
+            return;
+        };
+        let container_name = container.name().to_string();
+        if let Some(contents) = container.contents_mut() {
+            contents.push(item);
+        }
+        let owner = action.owner(world);
+        world.log().push(LogEvent::new(
+            format!(
+                "{} put the {item_name} into the {container_name}",
+                owner.name_for_actions()
+            ),
+            owner.pos,
+            LogCategory::Info,
+        ));
+    }
+}
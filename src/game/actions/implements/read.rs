@@ -2,6 +2,7 @@ use geometry::Direction;
 
 use super::super::{
     super::{
+        experience::Reward,
         log::{LogCategory, LogEvent},
         Avatar, World,
     },
@@ -30,11 +31,13 @@ impl ActionImpl for Read {
     fn on_finish(&self, action: &Action, world: &mut World) {
         if action.owner(world).is_player() {
             let pos = action.owner(world).pos + self.dir;
-            world.log().push(LogEvent::new(
-                world.map().get_tile(pos).read(),
-                pos,
-                LogCategory::Success,
-            ));
+            let text = world.map().get_tile(pos).read();
+            world
+                .log()
+                .push(LogEvent::new(text.clone(), pos, LogCategory::Success));
+            if world.player_mut().read_texts.insert(text) {
+                world.award_experience(Reward::Reading);
+            }
         }
     }
 }
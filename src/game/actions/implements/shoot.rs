@@ -0,0 +1,107 @@
+use geometry::Point;
+
+use super::{
+    super::{
+        super::{game_data::ItemTag, savage::Skill, Avatar, Hand, Item, World},
+        Action, ActionImpl,
+        ActionPossibility::{self, No},
+    },
+    projectile::{self, Missile},
+};
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Shoot {
+    pub target: Point,
+}
+
+/// Where the ammo is taken from
+#[derive(Copy, Clone)]
+enum Ammo {
+    Hand(Hand),
+    /// Indexes of the worn container and the item in it
+    Container(usize, usize),
+}
+
+impl Shoot {
+    fn launcher(actor: &Avatar) -> Option<(Hand, &Item)> {
+        actor
+            .hands
+            .iter()
+            .find(|(_, item)| item.proto.shooting.is_some())
+    }
+
+    fn find_ammo(actor: &Avatar, tag: ItemTag) -> Option<Ammo> {
+        if let Some((hand, _)) = actor
+            .hands
+            .iter()
+            .find(|(_, item)| item.tags().contains(&tag))
+        {
+            return Some(Ammo::Hand(hand));
+        }
+        actor.wear.iter().enumerate().find_map(|(i, container)| {
+            container
+                .contents()
+                .iter()
+                .position(|item| item.tags().contains(&tag))
+                .map(|j| Ammo::Container(i, j))
+        })
+    }
+
+    fn take_ammo(actor: &mut Avatar, ammo: Ammo) -> Option<Item> {
+        match ammo {
+            Ammo::Hand(hand) => actor.hands.take(hand),
+            Ammo::Container(i, j) => Some(actor.wear.get_mut(i)?.contents_mut()?.remove(j)),
+        }
+    }
+}
+
+impl ActionImpl for Shoot {
+    fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
+        let Some((_, launcher)) = Self::launcher(actor) else {
+            return No("You have nothing to shoot with".to_string());
+        };
+        let Some(ranged) = launcher.proto.shooting else {
+            return No("You have nothing to shoot with".to_string());
+        };
+        let Some(tag) = ranged.ammo else {
+            return No(format!("The {} has nothing to shoot", launcher.name()));
+        };
+        if Self::find_ammo(actor, tag).is_none() {
+            return No(format!("You have no ammo for the {}", launcher.name()));
+        }
+
+        projectile::is_possible(
+            actor,
+            world,
+            self.target,
+            ranged,
+            launcher.attack_time().round() as u32,
+        )
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = action.owner(world);
+        let Some((hand, launcher)) = Self::launcher(owner) else {
+            return;
+        };
+        let Some(ranged) = launcher.proto.shooting else {
+            return;
+        };
+        let Some(ammo) = ranged.ammo.and_then(|tag| Self::find_ammo(owner, tag)) else {
+            return;
+        };
+        let missile = Missile {
+            ranged,
+            skill: Skill::Shooting,
+            penalty: owner.hand_penalty(hand),
+        };
+        let Some(aim) = projectile::aim(action, world, &missile, self.target) else {
+            return;
+        };
+        let Some(item) = Self::take_ammo(action.owner_mut(world), ammo) else {
+            return;
+        };
+
+        projectile::fly(action, world, item, ranged, aim, self.target, "shoot");
+    }
+}
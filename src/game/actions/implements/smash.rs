@@ -0,0 +1,40 @@
+use geometry::Direction;
+
+use super::{
+    super::{
+        super::{Avatar, ItemQuality, World},
+        Action, ActionImpl, ActionPossibility,
+    },
+    breaking,
+};
+
+/// Smash a boulder to stones
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Smash {
+    pub dir: Direction,
+}
+
+impl ActionImpl for Smash {
+    fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
+        breaking::is_possible(
+            actor,
+            world,
+            self.dir,
+            ItemQuality::Smash,
+            "smash",
+            "a sledgehammer",
+        )
+    }
+
+    fn on_start(&self, action: &Action, world: &mut World) {
+        breaking::on_start(action, world, self.dir, "smashing");
+    }
+
+    fn on_step(&self, action: &Action, world: &mut World) {
+        breaking::on_step(action, world, self.dir);
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        breaking::on_finish(action, world, self.dir, "smashed");
+    }
+}
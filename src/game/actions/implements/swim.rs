@@ -0,0 +1,119 @@
+use std::f32::consts::SQRT_2;
+
+use geometry::Direction;
+
+use super::super::{
+    super::{
+        log::{LogCategory, LogEvent},
+        map::{TerrainInteract, TerrainView},
+        savage::{Modifiers, Skill, SkillLevel},
+        Avatar, Dice, World,
+    },
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+/// Ticks to swim one tile with d4 in Swimming
+const SWIM_TIME: f32 = 40.0;
+/// Totiks are born swimmers
+const AQUATIC_BONUS: i8 = 2;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Swim {
+    pub dir: Direction,
+}
+
+impl Swim {
+    /// Deep water should be swum into or out of
+    pub fn is_needed(actor: &Avatar, dir: Direction, world: &World) -> bool {
+        let mut map = world.map();
+        map.get_tile(actor.pos).terrain.is_deep_water()
+            || map.get_tile(actor.pos + dir).terrain.is_deep_water()
+    }
+}
+
+impl ActionImpl for Swim {
+    fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
+        if self.dir.is_here() {
+            return No("You can't swim in place".to_string());
+        }
+        if !Self::is_needed(actor, self.dir, world) {
+            return No("There is no deep water to swim".to_string());
+        }
+        let pos = actor.pos + self.dir;
+        let mut map = world.map();
+        let tile = map.get_tile(pos);
+        if !tile.terrain.is_deep_water() && !tile.terrain.is_passable() {
+            return No(format!("You can't get to the {}", tile.terrain.name()));
+        }
+        if let Some(unit_id) = tile.units.iter().copied().next() {
+            let unit = world.get_unit(unit_id);
+            return No(format!("{} is on the way", unit.name_for_actions()));
+        }
+
+        let skill = actor.char_sheet.skills.swimming;
+        // untrained swimmers are slower than d4 ones
+        let k_skill = match skill {
+            SkillLevel::D4_2 => 1.5,
+            skill => 4.0 / f32::from(Dice::from(skill).value()),
+        };
+        let k_race = if actor.personality.appearance.race.is_aquatic() {
+            0.5
+        } else {
+            1.0
+        };
+        let k_diagonal = match self.dir {
+            Direction::NorthEast
+            | Direction::SouthEast
+            | Direction::SouthWest
+            | Direction::NorthWest => SQRT_2,
+            _ => 1.0,
+        };
+        Yes(f32::round(SWIM_TIME * k_skill * k_race * k_diagonal) as u32)
+    }
+
+    /// Failed Swimming roll causes Fatigue, one can drown from Fatigue in deep water
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let unit = action.owner(world);
+        let bonus = if unit.personality.appearance.race.is_aquatic() {
+            AQUATIC_BONUS
+        } else {
+            0
+        };
+        let modifiers = Modifiers::default().with("aquatic", bonus);
+        let swimming = world.roll_skill(action.owner, Skill::Swimming, modifiers);
+        // the roll goes first, the action is finished again after a reroll
+        if !swimming.is_success() && world.offer_reroll(action, &swimming) {
+            return;
+        }
+        world.move_avatar(action.owner, self.dir);
+        if swimming.is_success() {
+            return;
+        }
+
+        let unit = action.owner(world);
+        let pos = unit.pos;
+        let name = unit.name_for_actions();
+        let still_in_water = world.map().get_tile(pos).terrain.is_deep_water();
+        if action.owner_mut(world).char_sheet.add_fatigue() {
+            world.log().push(
+                LogEvent::new(
+                    format!("{name} swallow water and get tired"),
+                    pos,
+                    LogCategory::Warning,
+                )
+                .with_roll(&swimming),
+            );
+        } else if still_in_water {
+            world.log().push(
+                LogEvent::new(
+                    format!("{name} run out of strength and drown"),
+                    pos,
+                    LogCategory::Danger,
+                )
+                .with_roll(&swimming),
+            );
+            world.kill_unit(action.owner);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use super::super::{
+    super::{
+        log::{LogCategory, LogEvent},
+        Avatar, World,
+    },
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct TakeOff {
+    /// Index of worn item
+    pub item_id: usize,
+}
+
+impl ActionImpl for TakeOff {
+    fn is_possible(&self, actor: &Avatar, _world: &World) -> ActionPossibility {
+        if let Err(reason) = actor.wear.can_take_off(self.item_id) {
+            return No(reason);
+        }
+        let item = actor.wear.get(self.item_id).unwrap();
+        match actor.hands.free_hand_for(item) {
+            Ok(..) => Yes(item.take_off_time().round() as u32),
+            Err(reason) => No(reason),
+        }
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = action.owner_mut(world);
+        let Some(item) = owner.wear.get(self.item_id) else {
+            return;
+        };
+        let Ok(hand) = owner.hands.free_hand_for(item) else {
+            return;
+        };
+        let item = owner.wear.remove(self.item_id);
+        let name = item.name().to_string();
+        owner.hands.put(hand, item);
+        let owner = action.owner(world);
+        world.log().push(LogEvent::new(
+            format!("{} took off the {name}", owner.name_for_actions()),
+            owner.pos,
+            LogCategory::Info,
+        ));
+    }
+}
@@ -0,0 +1,57 @@
+use super::super::{
+    super::{
+        log::{LogCategory, LogEvent},
+        Avatar, World,
+    },
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct TakeOut {
+    /// Index of worn container
+    pub container_id: usize,
+    /// Index of item inside the container
+    pub item_id: usize,
+}
+
+impl ActionImpl for TakeOut {
+    fn is_possible(&self, actor: &Avatar, _world: &World) -> ActionPossibility {
+        let Some(container) = actor.wear.get(self.container_id) else {
+            return No("You don't wear this".to_string());
+        };
+        let Some(item) = container.contents().get(self.item_id) else {
+            return No(format!("There is no such item in the {}", container.name()));
+        };
+        match actor.hands.free_hand_for(item) {
+            Ok(..) => Yes(item.wield_time().round() as u32),
+            Err(reason) => No(reason),
+        }
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = action.owner_mut(world);
+        let Some(container) = owner.wear.get_mut(self.container_id) else {
+            return;
+        };
+        let container_name = container.name().to_string();
+        let Some(contents) = container.contents_mut() else {
+            return;
+        };
+        let Ok(hand) = owner.hands.free_hand_for(&contents[self.item_id]) else {
+            return;
+        };
+        let item = contents.remove(self.item_id);
+        let item_name = item.name().to_string();
+        owner.hands.put(hand, item);
+        let owner = action.owner(world);
+        world.log().push(LogEvent::new(
+            format!(
+                "{} took the {item_name} out of the {container_name}",
+                owner.name_for_actions()
+            ),
+            owner.pos,
+            LogCategory::Info,
+        ));
+    }
+}
@@ -0,0 +1,55 @@
+use geometry::Point;
+
+use super::{
+    super::{
+        super::{savage::Skill, Avatar, Hand, World},
+        Action, ActionImpl,
+        ActionPossibility::{self, No},
+    },
+    projectile::{self, Missile},
+};
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Throw {
+    pub hand: Hand,
+    pub target: Point,
+}
+
+impl ActionImpl for Throw {
+    fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
+        let Some(item) = actor.hands.get(self.hand) else {
+            return No("You have nothing to throw".to_string());
+        };
+        let Some(ranged) = item.proto.throwing else {
+            return No(format!("The {} is not made for throwing", item.name()));
+        };
+
+        projectile::is_possible(
+            actor,
+            world,
+            self.target,
+            ranged,
+            item.attack_time().round() as u32,
+        )
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = action.owner(world);
+        let Some(ranged) = owner.hands.get(self.hand).and_then(|i| i.proto.throwing) else {
+            return;
+        };
+        let missile = Missile {
+            ranged,
+            skill: Skill::Athletics,
+            penalty: owner.hand_penalty(self.hand),
+        };
+        let Some(aim) = projectile::aim(action, world, &missile, self.target) else {
+            return;
+        };
+        let Some(item) = action.owner_mut(world).hands.take(self.hand) else {
+            return;
+        };
+
+        projectile::fly(action, world, item, ranged, aim, self.target, "throw");
+    }
+}
@@ -6,6 +6,7 @@ use super::super::{
     super::{
         log::{LogCategory, LogEvent},
         map::{Passage::Passable, TerrainInteract, TerrainView},
+        savage::BASE_PACE,
         Avatar, World,
     },
     Action, ActionImpl,
@@ -21,6 +22,16 @@ impl ActionImpl for Walk {
     fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
         let pos = actor.pos + self.dir;
         let mut map = world.map();
+        let current = &map.get_tile(actor.pos).terrain;
+        if current.is_deep_water() {
+            return No("You need to swim out of the deep water".to_string());
+        }
+        if current.is_climbable() {
+            return No(format!(
+                "You need to climb down from the {}",
+                current.name()
+            ));
+        }
         let tile = map.get_tile(pos);
         if !tile.terrain.is_passable() {
             return No(format!("You can't walk to the {}", tile.terrain.name()));
@@ -44,7 +55,8 @@ impl ActionImpl for Walk {
                 4..=10 => 3.0,
                 11.. => 1.0,
             };
-            let k = k_diagonal * k_age;
+            let k_pace = f32::from(BASE_PACE) / f32::from(actor.char_sheet.pace());
+            let k = k_diagonal * k_age * k_pace;
             if let Passable(pass_time) = tile.terrain.passage() {
                 f32::round(pass_time * k) as u32
             } else {
@@ -0,0 +1,40 @@
+use super::super::{
+    super::{
+        log::{LogCategory, LogEvent},
+        Avatar, Hand, World,
+    },
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Wear {
+    pub hand: Hand,
+}
+
+impl ActionImpl for Wear {
+    fn is_possible(&self, actor: &Avatar, _world: &World) -> ActionPossibility {
+        let Some(item) = actor.hands.get(self.hand) else {
+            return No("You have nothing in this hand".to_string());
+        };
+        match actor.wear.can_wear(item) {
+            Ok(..) => Yes(item.wear_time().round() as u32),
+            Err(reason) => No(reason),
+        }
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = action.owner_mut(world);
+        let Some(item) = owner.hands.take(self.hand) else {
+            return;
+        };
+        let name = item.name().to_string();
+        owner.wear.add(item);
+        let owner = action.owner(world);
+        world.log().push(LogEvent::new(
+            format!("{} put on the {name}", owner.name_for_actions()),
+            owner.pos,
+            LogCategory::Info,
+        ));
+    }
+}
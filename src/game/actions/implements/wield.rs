@@ -16,12 +16,12 @@ pub struct Wield {
 
 impl ActionImpl for Wield {
     fn is_possible(&self, actor: &Avatar, world: &World) -> ActionPossibility {
-        if !actor.wield.is_empty() {
-            return No("You already have something in your hands".to_string());
-        }
         let pos = actor.pos + self.dir;
         if let Some(item) = world.map().get_tile(pos).items.last() {
-            Yes(item.wield_time().round() as u32)
+            match actor.hands.free_hand_for(item) {
+                Ok(..) => Yes(item.wield_time().round() as u32),
+                Err(reason) => No(reason),
+            }
         } else {
             No("There is nothing to pick up".to_string())
         }
@@ -32,7 +32,13 @@ impl ActionImpl for Wield {
         let item = world.map().get_tile_mut(pos).items.pop();
         if let Some(item) = item {
             let name = item.name().to_string();
-            action.owner_mut(world).wield.push(item);
+            let hands = &mut action.owner_mut(world).hands;
+            if let Ok(hand) = hands.free_hand_for(&item) {
+                hands.put(hand, item);
+            } else {
+                world.map().get_tile_mut(pos).items.push(item);
+                return;
+            }
             world.log().push(LogEvent::new(
                 format!(
                     "{} wield the {}",
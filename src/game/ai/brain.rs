@@ -0,0 +1,94 @@
+use enum_dispatch::enum_dispatch;
+
+use super::{
+    super::{ActionType, World},
+    implements::{Flee, Follow, Wander},
+};
+
+#[enum_dispatch]
+pub trait BrainImpl {
+    /// Called every time unit is free to update brain state
+    fn plan(&mut self, _unit_id: usize, _world: &World) {}
+    /// Action unit wants to do right now
+    fn action(&self, unit_id: usize, world: &World) -> Option<ActionType>;
+}
+
+#[enum_dispatch(BrainImpl)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub enum Brain {
+    Wander,
+    Follow,
+    Flee,
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::Point;
+
+    use super::{
+        super::super::{
+            actions::implements::Skip,
+            map::{items::helpers::axe, terrains::Dirt},
+            world::tests::{add_npc, prepare_world},
+            Action, World,
+        },
+        Flee, Follow, Wander,
+    };
+
+    fn prepare_field(world: &World) {
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let mut map = world.map();
+                let tile = map.get_tile_mut(Point::new(x, y));
+                tile.terrain = Dirt::default().into();
+                tile.items.clear();
+            }
+        }
+    }
+
+    fn skip_ticks(world: &mut World, ticks: u32) {
+        for _ in 0..ticks {
+            world.player_mut().action = Some(Action::new(0, Skip {}.into(), world).unwrap());
+            world.tick();
+        }
+    }
+
+    #[test]
+    fn test_following() {
+        let mut world = prepare_world();
+        prepare_field(&world);
+        let npc = add_npc(&mut world, Point::new(5, 5));
+        world.get_unit_mut(npc).ai = Some(Follow::new(0).into());
+
+        skip_ticks(&mut world, 100);
+
+        assert!(world.get_unit(npc).pos.square_distance(Point::new(0, 0)) <= 2);
+    }
+
+    #[test]
+    fn test_fleeing() {
+        let mut world = prepare_world();
+        prepare_field(&world);
+        let npc = add_npc(&mut world, Point::new(1, 1));
+        world.get_unit_mut(npc).ai = Some(Flee::new(0).into());
+
+        skip_ticks(&mut world, 30);
+
+        assert!(world.get_unit(npc).pos.square_distance(Point::new(0, 0)) > 2);
+    }
+
+    #[test]
+    fn test_wandering_picks_up_items() {
+        let mut world = prepare_world();
+        prepare_field(&world);
+        let npc = add_npc(&mut world, Point::new(5, 5));
+        world.get_unit_mut(npc).ai = Some(Wander::default().into());
+        world.get_unit_mut(npc).hands.take_all();
+        world.map().get_tile_mut(Point::new(5, 6)).items.push(axe());
+
+        skip_ticks(&mut world, 50);
+
+        assert!(world.get_unit(npc).hands.main.is_some());
+        assert!(world.map().get_tile(Point::new(5, 6)).items.is_empty());
+    }
+}
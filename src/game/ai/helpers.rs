@@ -0,0 +1,16 @@
+use geometry::{Point, DIR8};
+
+use super::super::{actions::implements::Walk, Action, ActionType, World};
+
+/// Returns possible step with the lowest score, or `None` if unit is stuck
+pub fn best_step<F>(unit_id: usize, world: &World, score: F) -> Option<ActionType>
+where
+    F: Fn(Point) -> u32,
+{
+    let pos = world.get_unit(unit_id).pos;
+    DIR8.iter()
+        .copied()
+        .filter(|&dir| Action::new(unit_id, Walk { dir }.into(), world).is_ok())
+        .min_by_key(|&dir| score(pos + dir))
+        .map(|dir| Walk { dir }.into())
+}
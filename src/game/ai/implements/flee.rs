@@ -0,0 +1,44 @@
+use super::super::{
+    super::{actions::implements::Skip, ActionType, World},
+    helpers::best_step,
+    BrainImpl,
+};
+
+const SAFE_SQUARE_DISTANCE: u32 = 16 * 16;
+
+/// Runs away from the unit until it's far enough
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct Flee {
+    pub from: usize,
+    scared: bool,
+}
+
+impl Flee {
+    pub fn new(from: usize) -> Self {
+        Self {
+            from,
+            scared: false,
+        }
+    }
+}
+
+impl BrainImpl for Flee {
+    fn plan(&mut self, unit_id: usize, world: &World) {
+        let unit = world.get_unit(unit_id);
+        let danger = world.get_unit(self.from);
+        // one can't run from the danger that isn't noticed
+        self.scared = danger.is_alive()
+            && unit.noticed.contains(&self.from)
+            && danger.pos.square_distance(unit.pos) < SAFE_SQUARE_DISTANCE;
+    }
+
+    fn action(&self, unit_id: usize, world: &World) -> Option<ActionType> {
+        let step = if self.scared {
+            let danger = world.get_unit(self.from).pos;
+            best_step(unit_id, world, |p| u32::MAX - p.square_distance(danger))
+        } else {
+            None
+        };
+        step.or_else(|| Some(Skip {}.into()))
+    }
+}
@@ -0,0 +1,43 @@
+use geometry::Point;
+
+use super::super::{
+    super::{actions::implements::Skip, ActionType, World},
+    helpers::best_step,
+    BrainImpl,
+};
+
+/// Goes to the target and stays close to it, follows last seen position if target is lost
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct Follow {
+    pub target: usize,
+    last_seen: Option<Point>,
+}
+
+impl Follow {
+    pub fn new(target: usize) -> Self {
+        Self {
+            target,
+            last_seen: None,
+        }
+    }
+}
+
+impl BrainImpl for Follow {
+    fn plan(&mut self, unit_id: usize, world: &World) {
+        let target = world.get_unit(self.target);
+        if target.is_alive() && world.get_unit(unit_id).noticed.contains(&self.target) {
+            self.last_seen = Some(target.pos);
+        }
+    }
+
+    fn action(&self, unit_id: usize, world: &World) -> Option<ActionType> {
+        let pos = world.get_unit(unit_id).pos;
+        let step = match self.last_seen {
+            Some(target) if target.square_distance(pos) > 2 => {
+                best_step(unit_id, world, |p| p.square_distance(target))
+            }
+            _ => None,
+        };
+        step.or_else(|| Some(Skip {}.into()))
+    }
+}
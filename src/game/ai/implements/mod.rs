@@ -0,0 +1,7 @@
+pub use flee::Flee;
+pub use follow::Follow;
+pub use wander::Wander;
+
+mod flee;
+mod follow;
+mod wander;
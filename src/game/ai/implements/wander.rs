@@ -0,0 +1,51 @@
+use geometry::{Direction, DIR8, DIR9};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::super::{
+    super::{
+        actions::implements::{Skip, Walk, Wield},
+        Action, ActionType, World,
+    },
+    BrainImpl,
+};
+
+/// Chance to stay in place at a day
+const DAY_REST_CHANCE: f64 = 0.5;
+/// Wanderers mostly rest at night
+const NIGHT_REST_CHANCE: f64 = 0.9;
+
+/// Walks around randomly and picks up everything lying near
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
+pub struct Wander {}
+
+impl BrainImpl for Wander {
+    fn action(&self, unit_id: usize, world: &World) -> Option<ActionType> {
+        if world.get_unit(unit_id).hands.is_empty() {
+            let pick_up = DIR9
+                .iter()
+                .copied()
+                .map(|dir| Wield { dir }.into())
+                .find(|&typ| Action::new(unit_id, typ, world).is_ok());
+            if pick_up.is_some() {
+                return pick_up;
+            }
+        }
+
+        let rest_chance = if world.now().is_night() {
+            NIGHT_REST_CHANCE
+        } else {
+            DAY_REST_CHANCE
+        };
+        if world.rng().gen_bool(rest_chance) {
+            return Some(Skip {}.into());
+        }
+        let dirs: Vec<Direction> = DIR8
+            .iter()
+            .copied()
+            .filter(|&dir| Action::new(unit_id, Walk { dir }.into(), world).is_ok())
+            .collect();
+        let dir = dirs.choose(&mut *world.rng()).copied();
+        Some(dir.map_or(Skip {}.into(), |dir| Walk { dir }.into()))
+    }
+}
@@ -0,0 +1,5 @@
+pub use brain::{Brain, BrainImpl};
+
+mod brain;
+mod helpers;
+pub mod implements;
@@ -1,10 +1,20 @@
 #![allow(dead_code)]
 
+use std::collections::HashSet;
+
 use geometry::{Point, TwoDimDirection};
+use rand::Rng;
 
-use crate::game::map::items::helpers::{cloak, hat};
+use crate::game::map::items::helpers::{backpack, cloak, hat};
 
-use super::{races::Personality, savage::CharSheet, Action, Item};
+use super::{
+    ai::Brain,
+    dress::Dress,
+    hands::{Hand, Hands},
+    races::Personality,
+    savage::{Attribute, CharSheet, Modifiers, Skill, TraitRoll},
+    Action,
+};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct Avatar {
@@ -12,32 +22,57 @@ pub struct Avatar {
     pub pos: Point,
     pub action: Option<Action>,
     pub vision: TwoDimDirection,
-    // TODO: custom struct with hands counter and methods to return names and icons for UI
-    pub wield: Vec<Item>,
-    // TODO: custom struct with layers for dress and methods to return names and icons for UI
-    pub wear: Vec<Item>,
+    #[serde(default, alias = "wield")]
+    pub hands: Hands,
+    pub wear: Dress,
     pub char_sheet: CharSheet,
+    pub ai: Option<Brain>,
+    /// Units this one is aware of, they are forgotten when they go out of sight
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub noticed: HashSet<usize>,
+    /// The player and named NPCs roll an extra Wild Die
+    #[serde(default)]
+    pub wild_card: bool,
+    #[serde(default)]
+    pub bennies: u8,
+    /// Texts this unit has read, reading them again gives no experience
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub read_texts: HashSet<String>,
     // TODO: stamina
-    // TODO: traits
 }
 
 impl Avatar {
     pub fn new(personality: Personality, char_sheet: CharSheet, pos: Point) -> Self {
         Avatar {
-            personality,
             pos,
             action: None,
             vision: TwoDimDirection::East,
-            wield: Vec::new(),
-            wear: Vec::new(),
+            hands: Hands::default(),
+            wear: Dress::default(),
             char_sheet,
+            ai: None,
+            noticed: HashSet::new(),
+            wild_card: personality.is_player,
+            bennies: 0,
+            read_texts: HashSet::new(),
+            personality,
         }
     }
 
+    pub fn with_ai(mut self, ai: Brain) -> Self {
+        self.ai = Some(ai);
+        self
+    }
+
+    pub fn with_wild_card(mut self) -> Self {
+        self.wild_card = true;
+        self
+    }
+
     // TODO: remove this and select dress in create character scene
     pub fn dressed_default(personality: Personality, char_sheet: CharSheet, pos: Point) -> Self {
         Self {
-            wear: vec![hat(), cloak()],
+            wear: Dress::new(vec![hat(), cloak(), backpack()]),
             ..Self::new(personality, char_sheet, pos)
         }
     }
@@ -53,4 +88,42 @@ impl Avatar {
     pub fn is_player(&self) -> bool {
         self.personality.is_player
     }
+
+    pub fn is_alive(&self) -> bool {
+        self.personality.mind.alive
+    }
+
+    /// Penalty for using item in the given hand
+    pub fn hand_penalty(&self, hand: Hand) -> i8 {
+        Hands::penalty(hand, self.personality.mind.main_hand)
+    }
+
+    /// Wild Cards roll the Wild Die along with the trait die
+    pub fn roll_attribute<R: Rng + ?Sized>(
+        &self,
+        attribute: Attribute,
+        modifiers: Modifiers,
+        rng: &mut R,
+    ) -> TraitRoll {
+        self.char_sheet
+            .roll_attribute(attribute, modifiers.wild_card(self.wild_card), rng)
+    }
+
+    pub fn roll_skill<R: Rng + ?Sized>(
+        &self,
+        skill: Skill,
+        modifiers: Modifiers,
+        rng: &mut R,
+    ) -> TraitRoll {
+        self.char_sheet
+            .roll_skill(skill, modifiers.wild_card(self.wild_card), rng)
+    }
+
+    pub fn armor(&self) -> u8 {
+        self.wear.armor()
+    }
+
+    pub fn toughness(&self) -> u8 {
+        self.char_sheet.toughness(self.armor())
+    }
 }
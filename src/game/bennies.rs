@@ -0,0 +1,48 @@
+//! Bennies let the player reroll failed trait rolls and soak wounds
+
+use super::{savage::TraitRoll, Action};
+
+/// Wild Cards start every session with this number of Bennies
+pub const BENNIES_PER_SESSION: u8 = 3;
+/// Every cancelled wound lowers the damage by this value
+const DAMAGE_PER_WOUND: u8 = 4;
+
+/// Decision the player makes before the world goes on
+#[derive(Debug, Clone)]
+pub enum BennyPrompt {
+    /// Failed trait roll of the player's action, the action is finished again after a reroll
+    Reroll { action: Action, roll: TraitRoll },
+    /// Damage the player is about to take, `details` are breakdowns of the attack rolls
+    Soak {
+        attacker_name: String,
+        damage: u8,
+        details: Vec<String>,
+    },
+}
+
+impl BennyPrompt {
+    pub fn question(&self, bennies: u8) -> String {
+        let what = match self {
+            BennyPrompt::Reroll { .. } => "You failed the roll. Spend a Benny to reroll it?",
+            BennyPrompt::Soak { .. } => "You are about to be wounded. Spend a Benny to soak it?",
+        };
+        format!("{what} You have {bennies}. [Y/N]")
+    }
+}
+
+/// Every success and raise of a Vigor roll cancels one wound
+pub fn soaked_damage(damage: u8, soaked: u8) -> u8 {
+    damage.saturating_sub(soaked.saturating_mul(DAMAGE_PER_WOUND))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::soaked_damage;
+
+    #[test]
+    fn test_soaking() {
+        assert_eq!(13, soaked_damage(13, 0));
+        assert_eq!(9, soaked_damage(13, 1));
+        assert_eq!(0, soaked_damage(13, 5));
+    }
+}
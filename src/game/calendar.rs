@@ -0,0 +1,171 @@
+//! Calendar of the Midia followers.
+//!
+//! A year has four seasons of three months, every month has five weeks
+//! named after the peoples of the Midia, every week has six days.
+
+use std::fmt::{Display, Formatter};
+
+/// One tick is a tenth of a second
+pub const TICKS_PER_MINUTE: u128 = 600;
+pub const TICKS_PER_HOUR: u128 = TICKS_PER_MINUTE * 60;
+pub const TICKS_PER_DAY: u128 = TICKS_PER_HOUR * 24;
+pub const DAYS_PER_WEEK: u128 = 6;
+pub const WEEKS_PER_MONTH: u128 = 5;
+pub const DAYS_PER_MONTH: u128 = DAYS_PER_WEEK * WEEKS_PER_MONTH;
+pub const MONTHS_PER_YEAR: u128 = 12;
+pub const DAYS_PER_YEAR: u128 = DAYS_PER_MONTH * MONTHS_PER_YEAR;
+/// New world starts in the morning
+const START_HOUR: u128 = 8;
+/// Years are counted from the first gathering of the Midia followers
+const START_YEAR: u128 = 317;
+
+const WEEKS: [&str; WEEKS_PER_MONTH as usize] = ["Gazan", "Nyarnik", "Totik", "Lagnam", "Bug"];
+const MONTHS: [&str; MONTHS_PER_YEAR as usize] = [
+    "Thaw",
+    "Sprout",
+    "Bloom",
+    "Sunhigh",
+    "Dust",
+    "Harvest",
+    "Mist",
+    "Fallow",
+    "Ember",
+    "Frost",
+    "Longnight",
+    "Midia's Sleep",
+];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub fn name(self) -> &'static str {
+        match self {
+            Season::Spring => "spring",
+            Season::Summer => "summer",
+            Season::Autumn => "autumn",
+            Season::Winter => "winter",
+        }
+    }
+
+    /// Hours of sunrise and sunset, days are long in summer and short in winter
+    pub fn daylight(self) -> (f32, f32) {
+        match self {
+            Season::Spring | Season::Autumn => (7.0, 19.0),
+            Season::Summer => (5.0, 21.0),
+            Season::Winter => (9.0, 17.0),
+        }
+    }
+}
+
+/// Fractional hour, from 0.0 to 24.0, useful for smooth transitions
+pub fn hour_of_day(tick: u128) -> f32 {
+    let tick = (tick + START_HOUR * TICKS_PER_HOUR) % TICKS_PER_DAY;
+    tick as f32 / TICKS_PER_HOUR as f32
+}
+
+/// Date and time of the game world, months, weeks and days are counted from zero
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DateTime {
+    pub year: u128,
+    pub month: u8,
+    pub week: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl DateTime {
+    pub fn from_tick(tick: u128) -> Self {
+        let tick = tick + START_HOUR * TICKS_PER_HOUR;
+        let days = tick / TICKS_PER_DAY;
+        let day_of_year = days % DAYS_PER_YEAR;
+        let day_of_month = day_of_year % DAYS_PER_MONTH;
+        Self {
+            year: START_YEAR + days / DAYS_PER_YEAR,
+            month: (day_of_year / DAYS_PER_MONTH) as u8,
+            week: (day_of_month / DAYS_PER_WEEK) as u8,
+            day: day_of_month as u8,
+            hour: (tick % TICKS_PER_DAY / TICKS_PER_HOUR) as u8,
+            minute: (tick % TICKS_PER_HOUR / TICKS_PER_MINUTE) as u8,
+        }
+    }
+
+    pub fn season(self) -> Season {
+        match self.month / 3 {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+
+    pub fn month_name(self) -> &'static str {
+        MONTHS[self.month as usize]
+    }
+
+    pub fn week_name(self) -> &'static str {
+        WEEKS[self.week as usize]
+    }
+
+    /// For NPC schedules
+    pub fn is_night(self) -> bool {
+        let (sunrise, sunset) = self.season().daylight();
+        let hour = f32::from(self.hour);
+        hour < sunrise || hour >= sunset
+    }
+
+    pub fn time(self) -> String {
+        format!("{:02}:{:02}", self.hour, self.minute)
+    }
+
+    pub fn date(self) -> String {
+        format!("{} {} {}", self.day + 1, self.month_name(), self.year)
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.time(), self.date())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        hour_of_day, DateTime, Season, DAYS_PER_MONTH, DAYS_PER_WEEK, DAYS_PER_YEAR, TICKS_PER_DAY,
+        TICKS_PER_HOUR, TICKS_PER_MINUTE,
+    };
+
+    #[test]
+    fn test_calendar() {
+        let start = DateTime::from_tick(0);
+        assert_eq!("08:00, 1 Thaw 317", start.to_string());
+        assert_eq!("Gazan", start.week_name());
+        assert_eq!(Season::Spring, start.season());
+        assert!(!start.is_night());
+        assert!((hour_of_day(0) - 8.0).abs() < f32::EPSILON);
+
+        let midnight = TICKS_PER_HOUR * 16;
+        assert!(hour_of_day(midnight).abs() < f32::EPSILON);
+        let date = DateTime::from_tick(midnight + TICKS_PER_MINUTE * 5);
+        assert_eq!("00:05, 2 Thaw 317", date.to_string());
+        assert!(date.is_night());
+
+        let date = DateTime::from_tick(TICKS_PER_DAY * (DAYS_PER_MONTH * 4 + DAYS_PER_WEEK * 2));
+        assert_eq!("Totik", date.week_name());
+        assert_eq!("Dust", date.month_name());
+        assert_eq!(Season::Summer, date.season());
+
+        let date = DateTime::from_tick(TICKS_PER_DAY * (DAYS_PER_YEAR - 1));
+        assert_eq!("Bug", date.week_name());
+        assert_eq!("30 Midia's Sleep 317", date.date());
+        assert_eq!(Season::Winter, date.season());
+        assert_eq!(318, DateTime::from_tick(TICKS_PER_DAY * DAYS_PER_YEAR).year);
+    }
+}
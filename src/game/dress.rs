@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+use super::{traits::Name, Item};
+
+/// Worn items, only one item per slot on every layer
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(transparent)]
+pub struct Dress {
+    items: Vec<Item>,
+}
+
+impl Dress {
+    pub fn new(items: Vec<Item>) -> Self {
+        Self { items }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Item> {
+        self.items.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Item> {
+        self.items.get_mut(index)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Item> {
+        self.items.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn can_wear(&self, item: &Item) -> Result<(), String> {
+        let Some(wearable) = item.wearable() else {
+            return Err(format!("You can't wear the {}", item.name()));
+        };
+        for worn in &self.items {
+            let Some(worn_wearable) = worn.wearable() else {
+                continue;
+            };
+            let Some(slot) = wearable.slots.intersection(&worn_wearable.slots).next() else {
+                continue;
+            };
+            if worn_wearable.layer == wearable.layer {
+                return Err(format!(
+                    "You already wear the {} on your {}",
+                    worn.name(),
+                    slot.name()
+                ));
+            }
+            if worn_wearable.layer > wearable.layer {
+                return Err(format!("You need to take off the {} first", worn.name()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Outer layers should be taken off before the inner ones
+    pub fn can_take_off(&self, index: usize) -> Result<(), String> {
+        let Some(item) = self.items.get(index) else {
+            return Err("You don't wear this".to_string());
+        };
+        let Some(wearable) = item.wearable() else {
+            return Ok(());
+        };
+        if let Some(outer) = self.items.iter().find(|worn| {
+            worn.wearable()
+                .is_some_and(|w| w.layer > wearable.layer && !w.slots.is_disjoint(&wearable.slots))
+        }) {
+            return Err(format!("You need to take off the {} first", outer.name()));
+        }
+
+        Ok(())
+    }
+
+    /// Use [`Dress::can_wear`] to check there is a room for the item
+    pub fn add(&mut self, item: Item) {
+        self.items.push(item);
+    }
+
+    pub fn remove(&mut self, index: usize) -> Item {
+        self.items.remove(index)
+    }
+
+    pub fn take_all(&mut self) -> Vec<Item> {
+        self.items.drain(..).collect()
+    }
+
+    pub fn armor(&self) -> u8 {
+        self.items.iter().map(Item::armor).sum()
+    }
+
+    pub fn warmth(&self) -> u8 {
+        self.items.iter().map(Item::warmth).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::map::items::helpers::{backpack, cloak, hat, leather_armor},
+        Dress,
+    };
+
+    #[test]
+    fn test_layers() {
+        let mut dress = Dress::new(vec![hat()]);
+        assert!(dress.can_wear(&hat()).is_err());
+        dress.add(cloak());
+        // armor is worn under the cloak
+        assert!(dress.can_wear(&leather_armor()).is_err());
+        dress.remove(1);
+        assert!(dress.can_wear(&leather_armor()).is_ok());
+        dress.add(leather_armor());
+        dress.add(cloak());
+        assert!(dress.can_take_off(1).is_err());
+        assert!(dress.can_take_off(2).is_ok());
+        assert!(dress.can_wear(&backpack()).is_ok());
+        assert_eq!(leather_armor().armor(), dress.armor());
+    }
+}
@@ -0,0 +1,33 @@
+//! Experience the player earns, every `EXPERIENCE_PER_ADVANCE` of it gives an Advance
+
+/// Deeds worth experience
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Reward {
+    /// Seeing a chunk of the map for the first time
+    Discovery,
+    /// Reading a text the player has never read before
+    Reading,
+    /// Killing a unit
+    Kill,
+    /// Living through another day
+    Survival,
+}
+
+impl Reward {
+    pub fn experience(self) -> u32 {
+        match self {
+            Reward::Discovery => 1,
+            Reward::Reading => 2,
+            Reward::Kill | Reward::Survival => 5,
+        }
+    }
+
+    pub fn reason(self) -> &'static str {
+        match self {
+            Reward::Discovery => "discovering new lands",
+            Reward::Reading => "reading",
+            Reward::Kill => "the victory",
+            Reward::Survival => "surviving another day",
+        }
+    }
+}
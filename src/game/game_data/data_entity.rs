@@ -1,13 +1,19 @@
 use serde::Deserialize;
 
-use super::{item::ItemPrototype, names_pack::NamesPack};
+use super::{
+    edge::EdgePrototype, hindrance::HindrancePrototype, item::ItemPrototype, names_pack::NamesPack,
+    structure::StructurePrototype, terrain::TerrainPrototype,
+};
 
 #[derive(Deserialize, Debug)]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum DataEntity {
-    Item(ItemPrototype),
-    // TODO: terrains
+    Item(Box<ItemPrototype>),
+    Terrain(Box<TerrainPrototype>),
+    Structure(Box<StructurePrototype>),
+    Edge(Box<EdgePrototype>),
+    Hindrance(Box<HindrancePrototype>),
     NamesPack(NamesPack),
 }
 
@@ -15,7 +21,16 @@ pub enum DataEntity {
 mod tests {
     use crate::game::races::{Race, Sex};
 
-    use super::super::item::{ItemQuality, ItemSpecial, ItemTag};
+    use crate::game::{
+        savage::{Attribute, Skill},
+        Dice,
+    };
+
+    use super::super::{
+        hindrance::Severity,
+        item::{BodySlot, ItemQuality, ItemSpecial, ItemTag, Layer},
+        Effect,
+    };
     use super::DataEntity;
 
     fn check_shovel(shovel: &DataEntity) {
@@ -151,4 +166,85 @@ mod tests {
             panic!("Expected DataEntity::Item, got {:?}", slice[0]);
         }
     }
+
+    #[test]
+    fn test_deserialize_wearable() {
+        let json = r#"
+        [
+          {
+            "type": "item",
+            "id": "leather_armor",
+            "name": "leather armor",
+            "look_like": "leather_armor",
+            "mass": 4000,
+            "wearable": {
+              "slots": [ "TORSO", "BACK" ],
+              "layer": "NORMAL",
+              "armor": 1,
+              "warmth": 2
+            }
+          }
+        ]
+        "#;
+        let data: Vec<DataEntity> = serde_json::from_str(json).unwrap();
+        if let DataEntity::Item(item) = &data[0] {
+            let wearable = item.wearable.as_ref().unwrap();
+            assert!(wearable.slots.contains(&BodySlot::Torso));
+            assert!(wearable.slots.contains(&BodySlot::Back));
+            assert_eq!(Layer::Normal, wearable.layer);
+            assert_eq!(1, wearable.armor);
+            assert_eq!(2, wearable.warmth);
+        } else {
+            panic!("Expected DataEntity::Item, got {:?}", data[0]);
+        }
+    }
+
+    #[test]
+    fn test_deserialize_edge_and_hindrance() {
+        let json = r#"
+        [
+          {
+            "type": "edge",
+            "id": "brawny",
+            "name": "Brawny",
+            "description": "Big and strong",
+            "requirements": {
+              "races": [ "gazan" ],
+              "attributes": { "strength": "D6", "vigor": "D6" }
+            },
+            "effects": [ { "toughness": 1 } ]
+          },
+          {
+            "type": "hindrance",
+            "id": "clumsy",
+            "name": "Clumsy",
+            "description": "Trips over everything",
+            "severity": "major",
+            "effects": [
+              { "skill": { "skill": "athletics", "modifier": -2 } }
+            ]
+          }
+        ]
+        "#;
+        let data: Vec<DataEntity> = serde_json::from_str(json).unwrap();
+        if let DataEntity::Edge(edge) = &data[0] {
+            assert_eq!("brawny", edge.id);
+            assert_eq!(vec![Race::Gazan], edge.requirements.races);
+            assert_eq!(
+                Some(&Dice::D6),
+                edge.requirements.attributes.get(&Attribute::Strength)
+            );
+            assert!(edge.requirements.skills.is_empty());
+            assert_eq!(vec![Effect::Toughness(1)], edge.effects);
+        } else {
+            panic!("Expected DataEntity::Edge, got {:?}", data[0]);
+        }
+        if let DataEntity::Hindrance(hindrance) = &data[1] {
+            assert_eq!(Severity::Major, hindrance.severity);
+            assert_eq!(-2, hindrance.effects[0].skill_modifier(Skill::Athletics));
+            assert_eq!(0, hindrance.effects[0].skill_modifier(Skill::Stealth));
+        } else {
+            panic!("Expected DataEntity::Hindrance, got {:?}", data[1]);
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::game::{
+    races::Race,
+    savage::{Attribute, CharSheet, Skill},
+    Dice, SkillLevel,
+};
+
+use super::Effect;
+
+/// Edge is a special ability a character takes for Hindrance points or an Advance
+#[derive(Deserialize, Debug, Clone)]
+pub struct EdgePrototype {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub requirements: Requirements,
+    #[serde(default)]
+    pub effects: Vec<Effect>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct Requirements {
+    /// Any race if empty
+    #[serde(default)]
+    pub races: Vec<Race>,
+    /// Minimal dice of attributes
+    #[serde(default)]
+    pub attributes: HashMap<Attribute, Dice>,
+    /// Minimal levels of skills
+    #[serde(default)]
+    pub skills: HashMap<Skill, SkillLevel>,
+}
+
+impl Requirements {
+    pub fn are_met(&self, race: Race, char_sheet: &CharSheet) -> bool {
+        (self.races.is_empty() || self.races.contains(&race))
+            && self
+                .attributes
+                .iter()
+                .all(|(&a, &dice)| char_sheet.attributes.get_attribute(a) >= dice)
+            && self
+                .skills
+                .iter()
+                .all(|(&s, &level)| char_sheet.skills.get_skill(s) >= level)
+    }
+}
@@ -0,0 +1,67 @@
+use serde::Deserialize;
+
+use crate::game::savage::{Attribute, Skill};
+
+/// Mechanical effect of an Edge or a Hindrance
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Effect {
+    /// Modifier to every roll of the skill
+    Skill {
+        skill: Skill,
+        modifier: i8,
+    },
+    /// Modifier to every roll of the attribute
+    Attribute {
+        attribute: Attribute,
+        modifier: i8,
+    },
+    Toughness(i8),
+    /// Walking speed, usual Pace is 6
+    Pace(i8),
+    /// Vision range in tiles
+    Vision(i8),
+}
+
+impl Effect {
+    pub fn skill_modifier(self, skill: Skill) -> i8 {
+        match self {
+            Effect::Skill { skill: s, modifier } if s == skill => modifier,
+            _ => 0,
+        }
+    }
+
+    pub fn attribute_modifier(self, attribute: Attribute) -> i8 {
+        match self {
+            Effect::Attribute {
+                attribute: a,
+                modifier,
+            } if a == attribute => modifier,
+            _ => 0,
+        }
+    }
+
+    pub fn toughness(self) -> i8 {
+        if let Effect::Toughness(value) = self {
+            value
+        } else {
+            0
+        }
+    }
+
+    pub fn pace(self) -> i8 {
+        if let Effect::Pace(value) = self {
+            value
+        } else {
+            0
+        }
+    }
+
+    pub fn vision(self) -> i8 {
+        if let Effect::Vision(value) = self {
+            value
+        } else {
+            0
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use serde::Deserialize;
+
+use crate::game::traits::Name;
+
+use super::Effect;
+
+/// Hindrance is a flaw that gives points for Edges during the character creation
+#[derive(Deserialize, Debug, Clone)]
+pub struct HindrancePrototype {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub severity: Severity,
+    /// Some Hindrances are only roleplayed and have no effects
+    #[serde(default)]
+    pub effects: Vec<Effect>,
+}
+
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Minor,
+    Major,
+}
+
+impl Severity {
+    pub fn points(self) -> u8 {
+        match self {
+            Severity::Minor => 1,
+            Severity::Major => 2,
+        }
+    }
+}
+
+impl From<Severity> for &str {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Minor => "Minor",
+            Severity::Major => "Major",
+        }
+    }
+}
+
+impl Name for Severity {
+    fn name(&self) -> &'static str {
+        (*self).into()
+    }
+}
@@ -2,12 +2,20 @@ use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
 
+use crate::game::{traits::Name, Dice};
+
 #[derive(Serialize, Deserialize, Debug, Hash, Eq, PartialEq, Copy, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ItemTag {
     Tool,
     Weapon,
     Book,
+    /// Can be thrown into a pit to fill it without a shovel
+    Filler,
+    /// Ammo for bows
+    Arrow,
+    /// Ammo for slings
+    SlingAmmo,
 }
 
 #[derive(Serialize, Deserialize, Debug, Hash, Eq, PartialEq, Copy, Clone)]
@@ -16,6 +24,7 @@ pub enum ItemQuality {
     Dig,
     Butch,
     Cut,
+    Smash,
 }
 
 #[derive(Serialize, Deserialize, Debug, Hash, Eq, PartialEq, Copy, Clone)]
@@ -28,6 +37,42 @@ pub enum ItemSpecial {
     Colored,
 }
 
+#[derive(Serialize, Deserialize, Debug, Hash, Eq, PartialEq, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BodySlot {
+    Head,
+    Torso,
+    Back,
+    Legs,
+    Feet,
+    Hands,
+}
+
+impl Name for BodySlot {
+    fn name(&self) -> &'static str {
+        match self {
+            BodySlot::Head => "head",
+            BodySlot::Torso => "torso",
+            BodySlot::Back => "back",
+            BodySlot::Legs => "legs",
+            BodySlot::Feet => "feet",
+            BodySlot::Hands => "hands",
+        }
+    }
+}
+
+/// Outer layers are put on over inner ones
+#[derive(
+    Serialize, Deserialize, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Default,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Layer {
+    Under,
+    #[default]
+    Normal,
+    Outer,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ItemPrototype {
     pub id: String,
@@ -35,6 +80,9 @@ pub struct ItemPrototype {
     pub look_like: String,
     // in grams
     pub mass: u32,
+    // in millilitres
+    #[serde(default)]
+    pub volume: u32,
     #[serde(default)]
     pub tags: HashSet<ItemTag>,
     #[serde(default)]
@@ -43,7 +91,70 @@ pub struct ItemPrototype {
     pub specials: HashSet<ItemSpecial>,
     #[serde(default)]
     pub two_handed_tool: bool,
-    // TODO: struct with slots and armor values
+    /// Added to Strength roll when item is used in melee
+    #[serde(default)]
+    pub melee_damage: Option<Dice>,
+    /// Items with this can be worn
+    #[serde(default)]
+    pub wearable: Option<WearablePrototype>,
+    /// Items with this can hold other items
+    #[serde(default)]
+    pub container: Option<ContainerPrototype>,
+    /// Radius of emitted light
+    #[serde(default)]
+    pub light: u8,
+    /// Items with this shoot ammo
+    #[serde(default)]
+    pub shooting: Option<RangedPrototype>,
+    /// Items with this hurt when thrown
     #[serde(default)]
-    pub is_wearable: bool,
+    pub throwing: Option<RangedPrototype>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WearablePrototype {
+    pub slots: HashSet<BodySlot>,
+    #[serde(default)]
+    pub layer: Layer,
+    /// Added to Toughness when worn
+    #[serde(default)]
+    pub armor: u8,
+    #[serde(default)]
+    pub warmth: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct RangedPrototype {
+    /// Short, medium and long range in tiles
+    pub ranges: [u8; 3],
+    pub damage: Dice,
+    /// Strength is added to damage, like in melee
+    #[serde(default)]
+    pub strength: bool,
+    /// Launcher shoots items with this tag
+    #[serde(default)]
+    pub ammo: Option<ItemTag>,
+}
+
+impl RangedPrototype {
+    /// Penalty of the range band, `None` if target is out of range
+    pub fn range_penalty(self, square_distance: u32) -> Option<i8> {
+        let distance = (square_distance as f32).sqrt().round() as u32;
+        self.ranges
+            .iter()
+            .position(|&range| distance <= u32::from(range))
+            .map(|band| -2 * band as i8)
+    }
+
+    pub fn max_range(self) -> u8 {
+        self.ranges[2]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct ContainerPrototype {
+    // in millilitres
+    pub max_volume: u32,
+    // in grams
+    pub max_mass: u32,
 }
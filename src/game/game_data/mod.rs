@@ -3,24 +3,43 @@ use std::fs::File;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
 
-use once_cell::sync::OnceCell;
+use once_cell::sync::{Lazy, OnceCell};
 
 use data_entity::DataEntity;
-pub use item::{ItemPrototype, ItemQuality, ItemSpecial, ItemTag};
+pub use edge::EdgePrototype;
+pub use effect::Effect;
+pub use hindrance::HindrancePrototype;
+pub use item::{
+    ItemPrototype, ItemQuality, ItemSpecial, ItemTag, RangedPrototype, WearablePrototype,
+};
+pub use structure::{NpcSpawn, StructurePrototype};
+pub use terrain::TerrainPrototype;
 
 use crate::game::races::{Race, Sex};
 
 mod data_entity;
+mod edge;
+mod effect;
+mod hindrance;
 mod item;
 mod names_pack;
+mod structure;
+mod terrain;
 
 const PATH: &str = "data";
 static INSTANCE: OnceCell<GameData> = OnceCell::new();
+static PLACEHOLDER_TERRAIN: Lazy<TerrainPrototype> = Lazy::new(TerrainPrototype::placeholder);
+/// Terrains implemented in code, they have no prototypes in JSON
+const BUILT_IN_TERRAINS: [&str; 2] = ["dirt", "pit"];
 
 #[derive(Debug)]
 pub struct GameData {
     pub names: HashMap<Race, HashMap<Sex, Vec<String>>>,
     pub items: HashMap<String, ItemPrototype>,
+    pub terrains: HashMap<String, TerrainPrototype>,
+    pub structures: HashMap<String, StructurePrototype>,
+    pub edges: HashMap<String, EdgePrototype>,
+    pub hindrances: HashMap<String, HindrancePrototype>,
 }
 
 impl GameData {
@@ -30,13 +49,65 @@ impl GameData {
                 .map(|r| (r, Sex::iterator().map(|s| (s, Vec::new())).collect()))
                 .collect(),
             items: HashMap::with_capacity(10),
+            terrains: HashMap::with_capacity(10),
+            structures: HashMap::with_capacity(10),
+            edges: HashMap::with_capacity(10),
+            hindrances: HashMap::with_capacity(10),
         };
 
         data.load_dir(&PathBuf::from(PATH));
+        for error in data.check_terrain_ids() {
+            // TODO: implement logging
+            println!("{error}");
+        }
 
         data
     }
 
+    pub fn has_terrain(&self, id: &str) -> bool {
+        BUILT_IN_TERRAINS.contains(&id) || self.terrains.contains_key(id)
+    }
+
+    /// Prototype of the terrain or a placeholder for unknown ids
+    pub fn terrain(&self, id: &str) -> &TerrainPrototype {
+        self.terrains.get(id).unwrap_or(&PLACEHOLDER_TERRAIN)
+    }
+
+    /// Terrains refer to each other by ids, so they are checked after all files are loaded.
+    /// Structures with unknown terrains are dropped, unknown dig results become dirt.
+    fn check_terrain_ids(&mut self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let mut broken_structures = Vec::new();
+        for structure in self.structures.values() {
+            for tile in structure.legend.values() {
+                if let Some(terrain) = &tile.terrain {
+                    if !self.has_terrain(terrain) {
+                        errors.push(format!(
+                            "Structure {} has unknown terrain: {terrain}",
+                            structure.id
+                        ));
+                        broken_structures.push(structure.id.clone());
+                    }
+                }
+            }
+        }
+        for id in broken_structures {
+            self.structures.remove(&id);
+        }
+        for terrain in self.terrains.values() {
+            if let Some(dig_result) = &terrain.dig_result {
+                if !self.has_terrain(&dig_result.terrain) {
+                    errors.push(format!(
+                        "Terrain {} is dug into unknown terrain: {}",
+                        terrain.id, dig_result.terrain
+                    ));
+                }
+            }
+        }
+
+        errors
+    }
+
     fn load_dir(&mut self, path: &Path) {
         for entry in path.read_dir().unwrap() {
             let path = entry.unwrap().path();
@@ -67,7 +138,19 @@ impl GameData {
     fn add_entity(&mut self, entity: DataEntity) {
         match entity {
             DataEntity::Item(item) => {
-                self.items.insert(item.id.clone(), item);
+                self.items.insert(item.id.clone(), *item);
+            }
+            DataEntity::Terrain(terrain) => {
+                self.terrains.insert(terrain.id.clone(), *terrain);
+            }
+            DataEntity::Structure(structure) => {
+                self.structures.insert(structure.id.clone(), *structure);
+            }
+            DataEntity::Edge(edge) => {
+                self.edges.insert(edge.id.clone(), *edge);
+            }
+            DataEntity::Hindrance(hindrance) => {
+                self.hindrances.insert(hindrance.id.clone(), *hindrance);
             }
             DataEntity::NamesPack(name_pack) => {
                 for (race, value) in name_pack.names {
@@ -116,5 +199,38 @@ mod tests {
             .contains(&"Dragan".to_string()));
         assert!(data.items.len() > 0);
         assert!(data.items.contains_key("hat"));
+        assert!(data.terrains.contains_key("gravestone"));
+        assert!(data.structures.contains_key("ruined_hut"));
+        assert!(data.edges.contains_key("alertness"));
+        assert!(data.hindrances.contains_key("clumsy"));
+    }
+
+    #[test]
+    fn launchers_have_ammo() {
+        let data = GameData::load();
+        for item in data.items.values() {
+            if let Some(shooting) = item.shooting {
+                assert!(shooting.ammo.is_some(), "{} shoots nothing", item.id);
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_terrain_ids() {
+        let mut data = GameData::load();
+        assert!(data.check_terrain_ids().is_empty());
+        let structure = serde_json::from_str(
+            r#"{"id": "broken", "chance": 1.0, "biomes": [], "grid": ["x"],
+            "legend": {"x": {"terrain": "missing"}}}"#,
+        )
+        .unwrap();
+        data.structures.insert("broken".to_string(), structure);
+        let errors = data.check_terrain_ids();
+        assert_eq!(
+            vec!["Structure broken has unknown terrain: missing".to_string()],
+            errors
+        );
+        assert!(!data.structures.contains_key("broken"));
+        assert_eq!("unknown terrain", data.terrain("missing").name);
     }
 }
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::{map::Biome, races::Race};
+
+/// Prefab stamped into generated chunks
+#[derive(Deserialize, Debug, Clone)]
+pub struct StructurePrototype {
+    pub id: String,
+    /// Probability to appear in a chunk
+    pub chance: f64,
+    /// Biomes where structure can appear
+    pub biomes: Vec<Biome>,
+    /// Rows of tiles from north to south, every char is a key of the legend,
+    /// tiles with chars missing in the legend stay untouched
+    pub grid: Vec<String>,
+    pub legend: HashMap<char, StructureTile>,
+}
+
+impl StructurePrototype {
+    pub fn width(&self) -> i32 {
+        self.grid
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0) as i32
+    }
+
+    pub fn height(&self) -> i32 {
+        self.grid.len() as i32
+    }
+
+    pub fn tile(&self, x: i32, y: i32) -> Option<&StructureTile> {
+        let ch = self.grid.get(y as usize)?.chars().nth(x as usize)?;
+        self.legend.get(&ch)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct StructureTile {
+    /// Id of terrain from JSON or one of built-in "dirt" and "pit"
+    #[serde(default)]
+    pub terrain: Option<String>,
+    /// Ids of items
+    #[serde(default)]
+    pub items: Vec<String>,
+    #[serde(default)]
+    pub npc: Option<NpcSpawn>,
+}
+
+/// NPC created when the chunk with this tile is generated
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NpcSpawn {
+    /// Random if not set
+    #[serde(default)]
+    pub race: Option<Race>,
+}
@@ -0,0 +1,58 @@
+use serde::Deserialize;
+
+use crate::game::map::Passage;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TerrainPrototype {
+    pub id: String,
+    pub name: String,
+    /// Tileset regions, every tile picks one of them
+    pub looks_like: Vec<String>,
+    pub is_transparent: bool,
+    pub passage: Passage,
+    #[serde(default)]
+    pub dig_result: Option<DigResult>,
+    /// Text written on this terrain
+    #[serde(default)]
+    pub read: Option<String>,
+    #[serde(default)]
+    pub can_stock_items: bool,
+    /// Can't be walked through, only swum, items dropped here sink
+    #[serde(default)]
+    pub is_deep_water: bool,
+    /// Impassable, but one can climb on it
+    #[serde(default)]
+    pub is_climbable: bool,
+    /// Radius of emitted light
+    #[serde(default)]
+    pub light: u8,
+}
+
+impl TerrainPrototype {
+    /// Stands in for terrains missing in the game data,
+    /// like ones removed from JSON files after the world was saved
+    pub fn placeholder() -> Self {
+        Self {
+            id: "unknown".to_string(),
+            name: "unknown terrain".to_string(),
+            looks_like: vec!["dirt3".to_string()],
+            is_transparent: true,
+            passage: Passage::Passable(10.0),
+            dig_result: None,
+            read: None,
+            can_stock_items: false,
+            is_deep_water: false,
+            is_climbable: false,
+            light: 0,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DigResult {
+    /// Id of terrain from JSON or one of built-in "dirt" and "pit"
+    pub terrain: String,
+    /// Ids of items
+    #[serde(default)]
+    pub items: Vec<String>,
+}
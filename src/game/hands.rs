@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+
+use super::{races::MainHand, Item};
+
+/// Savage Worlds gives -2 for using the off hand
+pub const OFF_HAND_PENALTY: i8 = -2;
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Hand {
+    Main,
+    Off,
+}
+
+/// Two-handed tools are held in the main hand and leave the off hand busy
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(from = "HandsData")]
+pub struct Hands {
+    pub main: Option<Item>,
+    pub off: Option<Item>,
+}
+
+/// Older saves have a list of wielded items instead of hands
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HandsData {
+    Hands {
+        main: Option<Box<Item>>,
+        off: Option<Box<Item>>,
+    },
+    Wield(Vec<Item>),
+}
+
+impl From<HandsData> for Hands {
+    fn from(data: HandsData) -> Self {
+        match data {
+            HandsData::Hands { main, off } => Self {
+                main: main.map(|item| *item),
+                off: off.map(|item| *item),
+            },
+            HandsData::Wield(items) => {
+                let mut items = items.into_iter();
+                Self {
+                    main: items.next(),
+                    off: items.next(),
+                }
+            }
+        }
+    }
+}
+
+impl Hands {
+    pub fn get(&self, hand: Hand) -> Option<&Item> {
+        match hand {
+            Hand::Main => self.main.as_ref(),
+            Hand::Off => self.off.as_ref(),
+        }
+    }
+
+    pub fn take(&mut self, hand: Hand) -> Option<Item> {
+        match hand {
+            Hand::Main => self.main.take(),
+            Hand::Off => self.off.take(),
+        }
+    }
+
+    pub fn take_all(&mut self) -> Vec<Item> {
+        self.main
+            .take()
+            .into_iter()
+            .chain(self.off.take())
+            .collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Hand, &Item)> {
+        self.main
+            .iter()
+            .map(|i| (Hand::Main, i))
+            .chain(self.off.iter().map(|i| (Hand::Off, i)))
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &Item> {
+        self.main.iter().chain(self.off.iter())
+    }
+
+    /// First hand holding something, main hand is preferred
+    pub fn busy_hand(&self) -> Option<Hand> {
+        self.iter().next().map(|(hand, _)| hand)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.main.is_none() && self.off.is_none()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.is_two_handed() || (self.main.is_some() && self.off.is_some())
+    }
+
+    pub fn is_two_handed(&self) -> bool {
+        self.main.as_ref().is_some_and(Item::two_handed_tool)
+    }
+
+    /// Hand that will hold the item if there is a room for it
+    pub fn free_hand_for(&self, item: &Item) -> Result<Hand, String> {
+        if self.is_two_handed() {
+            return Err(format!(
+                "You hold the {} with both hands",
+                self.main.as_ref().unwrap().name()
+            ));
+        }
+        if item.two_handed_tool() {
+            return if self.is_empty() {
+                Ok(Hand::Main)
+            } else {
+                Err(format!("You need both hands free for the {}", item.name()))
+            };
+        }
+        if self.main.is_none() {
+            Ok(Hand::Main)
+        } else if self.off.is_none() {
+            Ok(Hand::Off)
+        } else {
+            Err("Both your hands are busy".to_string())
+        }
+    }
+
+    /// Use [`Hands::free_hand_for`] to check there is a room for the item
+    pub fn put(&mut self, hand: Hand, item: Item) {
+        match hand {
+            Hand::Main => self.main = Some(item),
+            Hand::Off => self.off = Some(item),
+        }
+    }
+
+    /// Item used to hit somebody, main hand is preferred
+    pub fn weapon(&self) -> Option<(Hand, &Item)> {
+        self.iter()
+            .find(|(_, item)| item.melee_damage().is_some())
+            .or_else(|| self.iter().next())
+    }
+
+    pub fn penalty(hand: Hand, main_hand: MainHand) -> i8 {
+        if hand == Hand::Off && main_hand != MainHand::Ambidexter {
+            OFF_HAND_PENALTY
+        } else {
+            0
+        }
+    }
+
+    pub fn display_name(&self) -> String {
+        match (&self.main, &self.off) {
+            (None, None) => "empty".to_string(),
+            (Some(main), None) if main.two_handed_tool() => {
+                format!("{} (both hands)", main.name())
+            }
+            (Some(main), None) => main.name().to_string(),
+            (None, Some(off)) => format!("{} (off hand)", off.name()),
+            (Some(main), Some(off)) => format!("{} and {}", main.name(), off.name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{
+            map::items::helpers::{axe, random_book, shovel},
+            races::MainHand,
+        },
+        Hand, Hands, OFF_HAND_PENALTY,
+    };
+
+    #[test]
+    fn test_one_handed_items() {
+        let mut hands = Hands::default();
+        assert_eq!(Ok(Hand::Main), hands.free_hand_for(&axe()));
+        hands.put(Hand::Main, axe());
+        assert_eq!(Ok(Hand::Off), hands.free_hand_for(&random_book()));
+        hands.put(Hand::Off, random_book());
+        assert!(hands.free_hand_for(&axe()).is_err());
+        assert_eq!("axe and strange book", hands.display_name());
+
+        let (hand, weapon) = hands.weapon().unwrap();
+        assert_eq!(Hand::Main, hand);
+        assert_eq!("axe", weapon.proto.id);
+
+        hands.take(Hand::Main);
+        assert_eq!(Ok(Hand::Main), hands.free_hand_for(&axe()));
+    }
+
+    #[test]
+    fn test_two_handed_items() {
+        let mut hands = Hands::default();
+        hands.put(Hand::Main, random_book());
+        assert!(hands.free_hand_for(&shovel()).is_err());
+
+        hands.take_all();
+        hands.put(Hand::Main, shovel());
+        assert!(hands.is_two_handed());
+        assert!(hands.free_hand_for(&random_book()).is_err());
+        assert_eq!("shovel (both hands)", hands.display_name());
+    }
+
+    #[test]
+    fn test_off_hand_penalty() {
+        assert_eq!(0, Hands::penalty(Hand::Main, MainHand::Left));
+        assert_eq!(OFF_HAND_PENALTY, Hands::penalty(Hand::Off, MainHand::Right));
+        assert_eq!(0, Hands::penalty(Hand::Off, MainHand::Ambidexter));
+    }
+}
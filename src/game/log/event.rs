@@ -1,6 +1,6 @@
 use geometry::Point;
 
-use super::LogCategory;
+use super::{super::savage::TraitRoll, LogCategory};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct LogEvent {
@@ -10,6 +10,9 @@ pub struct LogEvent {
     pub pos: Point,
     #[serde(rename = "c")]
     pub category: LogCategory,
+    /// Breakdowns of the rolls behind the event
+    #[serde(rename = "d", default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<String>,
 }
 
 impl LogEvent {
@@ -18,6 +21,17 @@ impl LogEvent {
             msg: msg.into(),
             pos,
             category,
+            details: Vec::new(),
         }
     }
+
+    pub fn with_roll(mut self, roll: &TraitRoll) -> Self {
+        self.details.push(roll.breakdown());
+        self
+    }
+
+    pub fn with_details(mut self, details: Vec<String>) -> Self {
+        self.details.extend(details);
+        self
+    }
 }
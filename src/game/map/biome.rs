@@ -0,0 +1,198 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use geometry::Point;
+use rand::{distributions::Standard, Rng};
+
+use super::{
+    terrains::{Boulder, Custom, Dirt, Grass, Tree},
+    Terrain,
+};
+
+/// Size of the biggest noise cell in tiles, approximate size of one biome region
+const REGION_SIZE: f32 = 64.0;
+const OCTAVES: u8 = 3;
+
+#[derive(serde::Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Biome {
+    Forest,
+    RockyHills,
+    Meadow,
+    Marsh,
+    Shallows,
+    Lake,
+}
+
+impl Biome {
+    /// Safe for spawning without swimming
+    pub fn is_habitable(self) -> bool {
+        matches!(self, Self::Forest | Self::Meadow)
+    }
+
+    /// Biome is calculated from the global tile position, so it is continuous across chunks
+    pub fn at(seed: u64, pos: Point) -> Self {
+        let height = fractal_noise(seed, pos);
+        let moisture = fractal_noise(seed.wrapping_add(1), pos);
+        if height < 0.27 {
+            Self::Lake
+        } else if height < 0.3 {
+            Self::Shallows
+        } else if height < 0.38 && moisture > 0.45 {
+            Self::Marsh
+        } else if height > 0.68 {
+            Self::RockyHills
+        } else if moisture > 0.55 {
+            Self::Forest
+        } else {
+            Self::Meadow
+        }
+    }
+
+    pub fn terrain<R: Rng + ?Sized>(self, rng: &mut R) -> Terrain {
+        match self {
+            Self::Forest => {
+                if rng.gen_bool(0.15) {
+                    Tree::new(rng.sample(Standard)).into()
+                } else if rng.gen_bool(0.005) {
+                    Boulder::new(rng.sample(Standard)).into()
+                } else if rng.gen_bool(0.6) {
+                    Grass::new(rng.sample(Standard)).into()
+                } else {
+                    Dirt::new(rng.sample(Standard)).into()
+                }
+            }
+            Self::RockyHills => {
+                if rng.gen_bool(0.12) {
+                    Boulder::new(rng.sample(Standard)).into()
+                } else if rng.gen_bool(0.005) {
+                    Tree::new(rng.sample(Standard)).into()
+                } else if rng.gen_bool(0.7) {
+                    Dirt::new(rng.sample(Standard)).into()
+                } else {
+                    Grass::new(rng.sample(Standard)).into()
+                }
+            }
+            Self::Meadow => {
+                if rng.gen_bool(0.003) {
+                    Tree::new(rng.sample(Standard)).into()
+                } else if rng.gen_bool(0.004) {
+                    Boulder::new(rng.sample(Standard)).into()
+                } else if rng.gen_bool(0.001) {
+                    Custom::random(
+                        if rng.gen_bool(0.5) {
+                            "gravestone"
+                        } else {
+                            "mound"
+                        },
+                        rng,
+                    )
+                    .into()
+                } else if rng.gen_bool(0.85) {
+                    Grass::new(rng.sample(Standard)).into()
+                } else {
+                    Dirt::new(rng.sample(Standard)).into()
+                }
+            }
+            Self::Marsh => {
+                if rng.gen_bool(0.3) {
+                    Custom::random("shallow_water", rng).into()
+                } else if rng.gen_bool(0.02) {
+                    Tree::new(rng.sample(Standard)).into()
+                } else if rng.gen_bool(0.5) {
+                    let mut grass = Grass::new(rng.sample(Standard));
+                    grass.die();
+                    grass.into()
+                } else {
+                    Dirt::new(rng.sample(Standard)).into()
+                }
+            }
+            Self::Shallows => {
+                if rng.gen_bool(0.9) {
+                    Custom::random("shallow_water", rng).into()
+                } else {
+                    Dirt::new(rng.sample(Standard)).into()
+                }
+            }
+            Self::Lake => Custom::random("deep_water", rng).into(),
+        }
+    }
+}
+
+/// Same for all chunks, used for noise that should be continuous between them
+pub fn noise_seed(world_seed: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    world_seed.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Random value in `0.0..1.0` for the lattice node
+fn node_value(seed: u64, octave: u8, x: i32, y: i32) -> f32 {
+    let mut hasher = DefaultHasher::new();
+    (seed, octave, x, y).hash(&mut hasher);
+    (hasher.finish() >> 40) as f32 / (1 << 24) as f32
+}
+
+/// Value noise smoothly interpolated between lattice nodes
+fn value_noise(seed: u64, octave: u8, x: f32, y: f32) -> f32 {
+    fn smooth(t: f32) -> f32 {
+        t * t * (3.0 - 2.0 * t)
+    }
+
+    let (x0, y0) = (x.floor(), y.floor());
+    let (tx, ty) = (smooth(x - x0), smooth(y - y0));
+    let (x0, y0) = (x0 as i32, y0 as i32);
+    let top = node_value(seed, octave, x0, y0)
+        + (node_value(seed, octave, x0 + 1, y0) - node_value(seed, octave, x0, y0)) * tx;
+    let bottom = node_value(seed, octave, x0, y0 + 1)
+        + (node_value(seed, octave, x0 + 1, y0 + 1) - node_value(seed, octave, x0, y0 + 1)) * tx;
+
+    top + (bottom - top) * ty
+}
+
+/// Sum of octaves with halving scale and amplitude, normalized to `0.0..1.0`
+fn fractal_noise(seed: u64, pos: Point) -> f32 {
+    let mut scale = REGION_SIZE;
+    let mut amplitude = 1.0;
+    let mut sum = 0.0;
+    let mut max = 0.0;
+    for octave in 0..OCTAVES {
+        sum += value_noise(seed, octave, pos.x as f32 / scale, pos.y as f32 / scale) * amplitude;
+        max += amplitude;
+        scale /= 2.0;
+        amplitude /= 2.0;
+    }
+
+    sum / max
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::Point;
+
+    use super::{fractal_noise, Biome};
+
+    #[test]
+    fn test_noise_is_continuous() {
+        for x in -100..100 {
+            let pos = Point::new(x, 31);
+            let delta = fractal_noise(42, pos) - fractal_noise(42, Point::new(x + 1, 32));
+            assert!(delta.abs() < 0.1, "noise jumps at {pos:?}");
+            assert!((0.0..1.0).contains(&fractal_noise(42, pos)));
+        }
+    }
+
+    #[test]
+    fn test_all_biomes_exist() {
+        let mut biomes = Vec::new();
+        for x in (-2000..2000).step_by(32) {
+            for y in (-2000..2000).step_by(32) {
+                let biome = Biome::at(42, Point::new(x, y));
+                if !biomes.contains(&biome) {
+                    biomes.push(biome);
+                }
+            }
+        }
+        assert_eq!(6, biomes.len());
+    }
+}
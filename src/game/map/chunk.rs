@@ -1,23 +1,48 @@
 use std::collections::{hash_map::DefaultHasher, HashSet};
 use std::hash::{Hash, Hasher};
 
-use arrayvec::ArrayVec;
-use rand::{distributions::Standard, rngs::StdRng, Rng, SeedableRng};
+use geometry::Point;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
 
-use crate::game::map::items::helpers::{axe, cloak, hat, random_book, shovel};
+use crate::game::{
+    game_data::NpcSpawn,
+    map::items::helpers::{
+        arrow, axe, bow, cloak, hat, leather_armor, pouch, random_book, shovel, sledgehammer,
+        sling, torch,
+    },
+};
 
 use super::{
-    terrains::{Boulder, Dirt, Grass, Tree},
-    ChunkPos, Tile,
+    biome::{noise_seed, Biome},
+    items::Item,
+    structure::stamp_structures,
+    ChunkPos, TerrainInteract, Tile, TilePos,
 };
 
+/// Items lying around in generated chunks with their weights,
+/// clothes and books are common, tools and weapons are rare finds
+const LOOT: [(fn() -> Item, u8); 12] = [
+    (cloak, 10),
+    (hat, 10),
+    (random_book, 8),
+    (pouch, 6),
+    (torch, 4),
+    (arrow, 4),
+    (leather_armor, 3),
+    (axe, 3),
+    (shovel, 3),
+    (sling, 2),
+    (bow, 1),
+    (sledgehammer, 1),
+];
+
 #[derive(Hash)]
-struct ChunkUnique {
+struct ChunkUnique<'a> {
     pos: ChunkPos,
-    world_seed: String,
+    world_seed: &'a str,
 }
 
-fn chunk_seed(world_seed: String, pos: ChunkPos) -> u64 {
+fn chunk_seed(world_seed: &str, pos: ChunkPos) -> u64 {
     let mut hasher = DefaultHasher::new();
     let seed = ChunkUnique { pos, world_seed };
     seed.hash(&mut hasher);
@@ -27,26 +52,24 @@ fn chunk_seed(world_seed: String, pos: ChunkPos) -> u64 {
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct Chunk {
     pub pos: ChunkPos,
-    pub tiles: ArrayVec<Tile, { Chunk::USIZE }>,
+    /// Boxed to not copy the whole chunk around the stack while it is deserialized
+    pub tiles: Box<[Tile]>,
+    /// NPCs from the structures waiting to be added to the world
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spawns: Vec<(Point, NpcSpawn)>,
 }
 
 impl Chunk {
     pub const SIZE: i32 = 32;
     pub const USIZE: usize = (Chunk::SIZE * Chunk::SIZE) as usize;
 
-    pub fn generate(world_seed: String, pos: ChunkPos) -> Self {
+    pub fn generate(world_seed: &str, pos: ChunkPos) -> Self {
+        let noise_seed = noise_seed(world_seed);
         let mut rng = StdRng::seed_from_u64(chunk_seed(world_seed, pos));
-        let mut tiles = ArrayVec::new();
-        for _ in 0..Chunk::USIZE {
-            tiles.push(Tile::new(if rng.gen_bool(0.005) {
-                Tree::new(rng.sample(Standard)).into()
-            } else if rng.gen_bool(0.01) {
-                Boulder::new(rng.sample(Standard)).into()
-            } else if rng.gen_bool(0.5) {
-                Grass::new(rng.sample(Standard)).into()
-            } else {
-                Dirt::new(rng.sample(Standard)).into()
-            }));
+        let mut tiles = Vec::with_capacity(Chunk::USIZE);
+        for i in 0..Chunk::USIZE {
+            let biome = Biome::at(noise_seed, Point::from_chunk(pos, i));
+            tiles.push(Tile::new(biome.terrain(&mut rng)));
         }
         let count: usize = rng.gen_range(5..20);
         let mut blocked_tiles = HashSet::with_capacity(100);
@@ -56,6 +79,9 @@ impl Chunk {
                 pos = rng.gen_range(0..Chunk::USIZE);
             }
             blocked_tiles.insert(pos);
+            if !tiles[pos].terrain.can_stock_items() {
+                continue;
+            }
             if pos > 0 {
                 blocked_tiles.insert(pos - 1);
             }
@@ -69,19 +95,33 @@ impl Chunk {
                 blocked_tiles.insert(pos + Chunk::SIZE as usize);
             }
 
-            tiles
-                .get_mut(pos)
-                .unwrap()
-                .items
-                .push(match rng.gen_range(0..5) {
-                    0 => cloak(),
-                    1 => hat(),
-                    2 => axe(),
-                    3 => shovel(),
-                    4 => random_book(),
-                    _ => unreachable!(),
-                });
+            if let Ok((item, _)) = LOOT.choose_weighted(&mut rng, |(_, weight)| *weight) {
+                tiles[pos].items.push(item());
+            }
+        }
+        let mut spawns = Vec::new();
+        stamp_structures(&mut tiles, &mut spawns, world_seed, noise_seed, pos);
+
+        Chunk {
+            pos,
+            tiles: tiles.into_boxed_slice(),
+            spawns,
         }
-        Chunk { pos, tiles }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chunk, ChunkPos};
+
+    #[test]
+    fn test_generation_is_deterministic() {
+        let pos = ChunkPos::new(3, -2);
+        let first = serde_json::to_string(&Chunk::generate("seed", pos)).unwrap();
+        let second = serde_json::to_string(&Chunk::generate("seed", pos)).unwrap();
+        assert_eq!(first, second);
+
+        let other = serde_json::to_string(&Chunk::generate("other", pos)).unwrap();
+        assert_ne!(first, other);
     }
 }
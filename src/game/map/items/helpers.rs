@@ -13,6 +13,21 @@ pub fn hat() -> Item {
     Item::new(game_data.items.get("hat").cloned().unwrap())
 }
 
+pub fn backpack() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("backpack").cloned().unwrap())
+}
+
+pub fn leather_armor() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("leather_armor").cloned().unwrap())
+}
+
+pub fn pouch() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("pouch").cloned().unwrap())
+}
+
 pub fn axe() -> Item {
     let game_data = GameData::instance();
     Item::new(game_data.items.get("axe").cloned().unwrap())
@@ -23,6 +38,51 @@ pub fn shovel() -> Item {
     Item::new(game_data.items.get("shovel").cloned().unwrap())
 }
 
+pub fn sledgehammer() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("sledgehammer").cloned().unwrap())
+}
+
+pub fn torch() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("torch").cloned().unwrap())
+}
+
+pub fn log() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("log").cloned().unwrap())
+}
+
+pub fn branch() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("branch").cloned().unwrap())
+}
+
+pub fn soil() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("soil").cloned().unwrap())
+}
+
+pub fn stone() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("stone").cloned().unwrap())
+}
+
+pub fn bow() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("bow").cloned().unwrap())
+}
+
+pub fn sling() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("sling").cloned().unwrap())
+}
+
+pub fn arrow() -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("arrow").cloned().unwrap())
+}
+
 pub fn random_book() -> Item {
     let game_data = GameData::instance();
     Item::new(game_data.items.get("book").cloned().unwrap())
@@ -30,3 +90,9 @@ pub fn random_book() -> Item {
         .with_named("strange book")
         .with_readable("Lore of the Midia")
 }
+
+pub fn corpse(name: &str) -> Item {
+    let game_data = GameData::instance();
+    Item::new(game_data.items.get("corpse").cloned().unwrap())
+        .with_named(format!("corpse of {name}"))
+}
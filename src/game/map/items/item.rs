@@ -2,30 +2,47 @@
 
 use std::collections::HashSet;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use tetra::graphics::Color;
 
-use crate::game::game_data::{ItemPrototype, ItemQuality, ItemSpecial, ItemTag};
+use crate::game::game_data::{ItemPrototype, ItemQuality, ItemSpecial, ItemTag, WearablePrototype};
+use crate::game::{Dice, GameData};
 
-use super::specials::{Colored, LookLike, Named, Readable};
+use super::specials::{Colored, Container, LookLike, Named, Readable};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Item {
+    #[serde(deserialize_with = "current_proto")]
     pub proto: ItemPrototype,
     pub named: Option<Named>,
     pub colored: Option<Colored>,
     pub readable: Option<Readable>,
     pub look_like: Option<LookLike>,
+    #[serde(default)]
+    pub container: Option<Container>,
+}
+
+/// Prototypes are saved whole, so the ones from older saves lack the fields added later,
+/// the prototype from the game data is used instead if it still exists
+fn current_proto<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ItemPrototype, D::Error> {
+    let saved = ItemPrototype::deserialize(deserializer)?;
+    Ok(GameData::instance()
+        .items
+        .get(&saved.id)
+        .cloned()
+        .unwrap_or(saved))
 }
 
 impl Item {
     pub fn new(proto: ItemPrototype) -> Self {
+        let container = proto.container.map(|_| Container::default());
         Self {
             proto,
             named: None,
             colored: None,
             readable: None,
             look_like: None,
+            container,
         }
     }
 
@@ -94,7 +111,51 @@ impl Item {
     }
 
     pub fn mass(&self) -> u32 {
-        self.proto.mass
+        self.proto.mass + self.contents().iter().map(Item::mass).sum::<u32>()
+    }
+
+    pub fn volume(&self) -> u32 {
+        self.proto.volume
+    }
+
+    pub fn is_container(&self) -> bool {
+        self.container.is_some()
+    }
+
+    pub fn contents(&self) -> &[Item] {
+        self.container
+            .as_ref()
+            .map_or(&[], |container| container.items.as_slice())
+    }
+
+    pub fn contents_mut(&mut self) -> Option<&mut Vec<Item>> {
+        self.container
+            .as_mut()
+            .map(|container| &mut container.items)
+    }
+
+    pub fn can_hold(&self, item: &Item) -> Result<(), String> {
+        let Some(limits) = self.proto.container else {
+            return Err(format!("The {} is not a container", self.name()));
+        };
+        let volume: u32 = self.contents().iter().map(Item::volume).sum();
+        if volume + item.volume() > limits.max_volume {
+            return Err(format!(
+                "There is no room for the {} in the {}",
+                item.name(),
+                self.name()
+            ));
+        }
+        let mass: u32 = self.contents().iter().map(Item::mass).sum();
+        if mass + item.mass() > limits.max_mass {
+            return Err(format!(
+                "The {} is too heavy for the {}",
+                item.name(),
+                self.name()
+            ));
+        }
+
+        Ok(())
     }
 
     pub fn two_handed_tool(&self) -> bool {
@@ -109,12 +170,32 @@ impl Item {
         self.proto.tags.contains(&ItemTag::Weapon)
     }
 
+    pub fn melee_damage(&self) -> Option<Dice> {
+        self.proto.melee_damage
+    }
+
     pub fn tool_or_weapon(&self) -> bool {
         self.is_tool() || self.is_weapon()
     }
 
     pub fn is_wearable(&self) -> bool {
-        self.proto.is_wearable
+        self.proto.wearable.is_some()
+    }
+
+    pub fn wearable(&self) -> Option<&WearablePrototype> {
+        self.proto.wearable.as_ref()
+    }
+
+    pub fn armor(&self) -> u8 {
+        self.wearable().map_or(0, |w| w.armor)
+    }
+
+    pub fn warmth(&self) -> u8 {
+        self.wearable().map_or(0, |w| w.warmth)
+    }
+
+    pub fn light(&self) -> u8 {
+        self.proto.light
     }
 
     pub fn is_readable(&self) -> bool {
@@ -125,6 +206,11 @@ impl Item {
         self.proto.tags.contains(&ItemTag::Book)
     }
 
+    pub fn attack_time(&self) -> f32 {
+        // 100 grams per tick above the unarmed attack
+        10.0 + self.mass() as f32 / 100.0
+    }
+
     pub fn drop_time(&self) -> f32 {
         // 1000 grams per tick
         self.mass() as f32 / 1000.0
@@ -134,7 +220,57 @@ impl Item {
         // 100 grams per tick
         self.mass() as f32 / 100.0
     }
+
+    pub fn wear_time(&self) -> f32 {
+        // 10 grams per tick
+        self.mass() as f32 / 10.0
+    }
+
+    pub fn take_off_time(&self) -> f32 {
+        // 20 grams per tick
+        self.mass() as f32 / 20.0
+    }
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::{
+        super::helpers::{axe, backpack, cloak, pouch, random_book, shovel},
+        Item,
+    };
+
+    #[test]
+    fn test_container_capacity() {
+        let mut pouch = pouch();
+        assert!(pouch.is_container());
+        assert!(!axe().is_container());
+        assert!(axe().can_hold(&shovel()).is_err());
+        assert!(pouch.can_hold(&axe()).is_err());
+
+        let mut backpack = backpack();
+        let empty_mass = backpack.mass();
+        assert!(backpack.can_hold(&axe()).is_ok());
+        backpack.contents_mut().unwrap().push(axe());
+        backpack.contents_mut().unwrap().push(shovel());
+        assert_eq!(empty_mass + axe().mass() + shovel().mass(), backpack.mass());
+
+        pouch.contents_mut().unwrap().push(random_book());
+        assert!(backpack.can_hold(&pouch).is_ok());
+    }
+
+    /// Items of older saves had `is_wearable` instead of the wearable prototype
+    #[test]
+    fn test_load_old_prototype() {
+        let mut json = serde_json::to_value(cloak()).unwrap();
+        let proto = json["proto"].as_object_mut().unwrap();
+        proto.remove("wearable");
+        proto.insert("is_wearable".to_string(), true.into());
+
+        let item: Item = serde_json::from_value(json).unwrap();
+        assert!(item.is_wearable());
+        assert_eq!(
+            cloak().wearable().unwrap().warmth,
+            item.wearable().unwrap().warmth
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use tetra::graphics::Color;
 
+use super::Item;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Named {
     pub name: String,
@@ -21,6 +23,7 @@ pub struct LookLike {
     pub look_like: String,
 }
 
-// TODO: slots and stuff
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Wearable {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Container {
+    pub items: Vec<Item>,
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use geometry::Point;
+
+use super::{
+    super::calendar::{hour_of_day, DateTime},
+    field_of_view_set, Map,
+};
+
+/// Hours between dawn and sunrise, and between sunset and dusk
+const TWILIGHT: f32 = 2.0;
+/// Light of the moon and the stars
+const NIGHT_LIGHT: f32 = 0.05;
+/// Tiles lit less than this can't be seen
+pub const MIN_VISIBLE_LIGHT: f32 = 0.1;
+pub const MAX_LIGHT_RADIUS: u8 = 16;
+
+/// Sunlight, from `NIGHT_LIGHT` at night to 1.0 at day
+pub fn ambient_light(tick: u128) -> f32 {
+    let hour = hour_of_day(tick);
+    let (sunrise, sunset) = DateTime::from_tick(tick).season().daylight();
+    let sun = if !(sunrise - TWILIGHT..sunset + TWILIGHT).contains(&hour) {
+        0.0
+    } else if hour < sunrise {
+        (hour - sunrise + TWILIGHT) / TWILIGHT
+    } else if hour < sunset {
+        1.0
+    } else {
+        (sunset + TWILIGHT - hour) / TWILIGHT
+    };
+    NIGHT_LIGHT + (1.0 - NIGHT_LIGHT) * sun
+}
+
+/// Light levels of tiles, from 0.0 (pitch black) to 1.0 (daylight)
+#[derive(Debug)]
+pub struct Lighting {
+    ambient: f32,
+    emitted: HashMap<Point, f32>,
+}
+
+impl Lighting {
+    pub fn new(ambient: f32) -> Self {
+        Self {
+            ambient,
+            emitted: HashMap::new(),
+        }
+    }
+
+    /// Light fades with distance and doesn't pass through opaque terrain
+    pub fn add_emitter(&mut self, pos: Point, radius: u8, map: &Map) {
+        let radius = radius.min(MAX_LIGHT_RADIUS);
+        let range = f32::from(radius) + 1.0;
+        for point in field_of_view_set(pos, i32::from(radius), map) {
+            let distance = (pos.square_distance(point) as f32).sqrt();
+            let level = 1.0 - distance / range;
+            let lit = self.emitted.entry(point).or_insert(0.0);
+            *lit = lit.max(level);
+        }
+    }
+
+    pub fn level(&self, pos: Point) -> f32 {
+        self.emitted
+            .get(&pos)
+            .copied()
+            .unwrap_or(0.0)
+            .max(self.ambient)
+    }
+
+    pub fn is_lit(&self, pos: Point) -> bool {
+        self.level(pos) >= MIN_VISIBLE_LIGHT
+    }
+}
+
+impl Default for Lighting {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::super::calendar::{DAYS_PER_MONTH, TICKS_PER_DAY, TICKS_PER_HOUR},
+        ambient_light, MIN_VISIBLE_LIGHT,
+    };
+
+    #[test]
+    fn test_day_and_night() {
+        assert!((ambient_light(0) - 1.0).abs() < f32::EPSILON);
+        let midnight = TICKS_PER_HOUR * 16;
+        assert!(ambient_light(midnight) < MIN_VISIBLE_LIGHT);
+        assert!(ambient_light(midnight - TICKS_PER_HOUR * 4) > MIN_VISIBLE_LIGHT);
+        assert!(ambient_light(midnight - TICKS_PER_HOUR * 4) < 1.0);
+        assert!((ambient_light(5) - ambient_light(TICKS_PER_DAY + 5)).abs() < f32::EPSILON);
+        // winter mornings are still dark at 8 o'clock
+        let winter = TICKS_PER_DAY * DAYS_PER_MONTH * 9;
+        assert!(ambient_light(winter) < 1.0);
+        assert!((ambient_light(winter + TICKS_PER_HOUR * 2) - 1.0).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,63 @@
+use geometry::Point;
+
+use super::{FovMap, Map};
+
+/// Points of a straight line, both ends included
+pub fn line(from: Point, to: Point) -> Vec<Point> {
+    let (dx, dy) = ((to.x - from.x).abs(), -(to.y - from.y).abs());
+    let (sx, sy) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+    let mut error = dx + dy;
+    let mut current = from;
+    let mut points = Vec::with_capacity((dx - dy) as usize + 1);
+    points.push(current);
+    while current != to {
+        let doubled = 2 * error;
+        if doubled >= dy {
+            error += dy;
+            current.x += sx;
+        }
+        if doubled <= dx {
+            error += dx;
+            current.y += sy;
+        }
+        points.push(current);
+    }
+
+    points
+}
+
+/// Nothing opaque between the points, the ends themselves don't matter
+pub fn line_of_sight(from: Point, to: Point, map: &Map) -> bool {
+    let points = line(from, to);
+    points
+        .iter()
+        .skip(1)
+        .take(points.len().saturating_sub(2))
+        .all(|&p| map.is_transparent(p))
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::Point;
+
+    use super::line;
+
+    #[test]
+    fn test_line() {
+        assert_eq!(
+            vec![Point::new(0, 0)],
+            line(Point::new(0, 0), Point::new(0, 0))
+        );
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(1, 1), Point::new(2, 2)],
+            line(Point::new(0, 0), Point::new(2, 2))
+        );
+        let points = line(Point::new(3, -1), Point::new(-4, 2));
+        assert_eq!(8, points.len());
+        assert_eq!(Point::new(3, -1), points[0]);
+        assert_eq!(Point::new(-4, 2), points[7]);
+        for pair in points.windows(2) {
+            assert!(pair[0].square_distance(pair[1]) <= 2);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Chunk, TerrainView, Tile};
+
+/// Topmost item the player saw on a tile
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct ItemMemory {
+    #[serde(rename = "l")]
+    pub looks_like: String,
+    #[serde(rename = "n")]
+    pub name: String,
+}
+
+/// How the tile looked when the player saw it last time
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct TileMemory {
+    #[serde(rename = "l")]
+    pub looks_like: String,
+    #[serde(rename = "n")]
+    pub name: String,
+    #[serde(rename = "i", default, skip_serializing_if = "Option::is_none")]
+    pub item: Option<ItemMemory>,
+}
+
+impl TileMemory {
+    pub fn new(tile: &Tile) -> Self {
+        Self {
+            looks_like: tile.terrain.looks_like().to_string(),
+            name: tile.terrain.name().to_string(),
+            item: tile.top_item().map(|item| ItemMemory {
+                looks_like: item.look_like().to_string(),
+                name: item.name().to_string(),
+            }),
+        }
+    }
+
+    /// Compares without allocations, it is called for every visible tile
+    pub fn is_of(&self, tile: &Tile) -> bool {
+        self.looks_like == tile.terrain.looks_like()
+            && self.name == tile.terrain.name()
+            && match (&self.item, tile.top_item()) {
+                (None, None) => true,
+                (Some(memory), Some(item)) => {
+                    memory.looks_like == item.look_like() && memory.name == item.name()
+                }
+                _ => false,
+            }
+    }
+}
+
+/// Remembered tiles of a chunk, every distinct memory is stored only once
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ChunkMemory {
+    #[serde(rename = "p")]
+    palette: Vec<TileMemory>,
+    /// Index in the palette plus one for every tile, zero is for never seen tiles
+    #[serde(rename = "t")]
+    tiles: Vec<u16>,
+}
+
+impl ChunkMemory {
+    pub fn is_empty(&self) -> bool {
+        self.palette.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&TileMemory> {
+        let id = self.tiles.get(index)?.checked_sub(1)?;
+        self.palette.get(id as usize)
+    }
+
+    /// Returns `true` if the memory of the tile has changed
+    pub fn remember(&mut self, index: usize, tile: &Tile) -> bool {
+        if self.get(index).is_some_and(|memory| memory.is_of(tile)) {
+            return false;
+        }
+        if self.tiles.is_empty() {
+            self.tiles = vec![0; Chunk::USIZE];
+        }
+        let id = if let Some(id) = self.palette.iter().position(|m| m.is_of(tile)) {
+            id
+        } else {
+            if self.palette.len() >= Chunk::USIZE {
+                self.forget_unused();
+            }
+            self.palette.push(TileMemory::new(tile));
+            self.palette.len() - 1
+        };
+        self.tiles[index] = id as u16 + 1;
+
+        true
+    }
+
+    /// Drops palette entries no tile refers to anymore
+    fn forget_unused(&mut self) {
+        let mut palette = Vec::with_capacity(self.palette.len());
+        let mut new_ids = vec![0; self.palette.len() + 1];
+        for id in &mut self.tiles {
+            if *id == 0 {
+                continue;
+            }
+            if new_ids[*id as usize] == 0 {
+                palette.push(self.palette[*id as usize - 1].clone());
+                new_ids[*id as usize] = palette.len() as u16;
+            }
+            *id = new_ids[*id as usize];
+        }
+        self.palette = palette;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{items::helpers::axe, terrains::Dirt, Chunk, Tile},
+        ChunkMemory,
+    };
+
+    #[test]
+    fn test_chunk_memory() {
+        let mut memory = ChunkMemory::default();
+        assert!(memory.is_empty());
+        assert!(memory.get(0).is_none());
+
+        let mut tile = Tile::new(Dirt::default().into());
+        assert!(memory.remember(0, &tile));
+        assert!(!memory.remember(0, &tile));
+        assert!(memory.remember(1, &tile));
+        assert_eq!(1, memory.palette.len());
+
+        tile.items.push(axe());
+        assert!(memory.remember(1, &tile));
+        assert_eq!(2, memory.palette.len());
+        assert!(memory.get(0).unwrap().item.is_none());
+        assert_eq!("axe", memory.get(1).unwrap().item.as_ref().unwrap().name);
+        assert!(memory.get(2).is_none());
+
+        let json = serde_json::to_string(&memory).unwrap();
+        let loaded: ChunkMemory = serde_json::from_str(&json).unwrap();
+        assert!(loaded.get(1).unwrap().is_of(&tile));
+        assert_eq!(Chunk::USIZE, loaded.tiles.len());
+    }
+}
@@ -2,43 +2,59 @@ use std::collections::{HashMap, HashSet};
 
 use geometry::Point;
 
+use super::game_data::NpcSpawn;
+
+pub use biome::Biome;
 pub use chunk::Chunk;
 pub use fov::{field_of_view_set, Fov, FovMap};
 pub use items::Item;
+pub use light::{ambient_light, Lighting, MAX_LIGHT_RADIUS, MIN_VISIBLE_LIGHT};
+pub use line::{line, line_of_sight};
+pub use memory::{ChunkMemory, TileMemory};
 pub use passage::Passage;
+pub use pathfinding::find_path;
 pub use pos::{ChunkPos, TilePos};
 pub use terrain::{Terrain, TerrainInteract, TerrainView};
 pub use tile::Tile;
 
+mod biome;
 mod chunk;
 mod fov;
 pub mod items;
+mod light;
+mod line;
+mod memory;
 mod passage;
+mod pathfinding;
 mod pos;
+mod structure;
 mod terrain;
 pub mod terrains;
 mod tile;
 
+const MAX_START_SEARCH_RADIUS: i32 = 256;
+
 pub struct Map {
     pub seed: String,
     pub chunks: HashMap<ChunkPos, Chunk>,
     pub changed: HashSet<ChunkPos>,
+    /// What the player saw before, it is saved apart from chunks
+    /// to not save every seen chunk whole
+    pub memory: HashMap<ChunkPos, ChunkMemory>,
 }
 
 impl Map {
     pub fn get_chunk(&mut self, pos: ChunkPos) -> &Chunk {
-        let seed = self.seed.clone();
         self.chunks
             .entry(pos)
-            .or_insert_with_key(|pos| Chunk::generate(seed, *pos))
+            .or_insert_with_key(|pos| Chunk::generate(&self.seed, *pos))
     }
 
     pub fn get_chunk_mut(&mut self, pos: ChunkPos) -> &mut Chunk {
-        let seed = self.seed.clone();
         self.changed.insert(pos);
         self.chunks
             .entry(pos)
-            .or_insert_with_key(|pos| Chunk::generate(seed, *pos))
+            .or_insert_with_key(|pos| Chunk::generate(&self.seed, *pos))
     }
 
     pub fn get_tile(&mut self, pos: Point) -> &Tile {
@@ -53,6 +69,64 @@ impl Map {
         &mut chunk.tiles[pos]
     }
 
+    /// Nearest passable tile in forest or meadow, to not start the game in the middle of a lake
+    pub fn find_start_pos(&mut self, from: Point) -> Point {
+        let noise_seed = biome::noise_seed(&self.seed);
+        for radius in 0..MAX_START_SEARCH_RADIUS {
+            for x in -radius..=radius {
+                for y in -radius..=radius {
+                    if x.abs() != radius && y.abs() != radius {
+                        continue;
+                    }
+                    let pos = from + Point::new(x, y);
+                    if Biome::at(noise_seed, pos).is_habitable()
+                        && self.get_tile(pos).terrain.is_passable()
+                    {
+                        return pos;
+                    }
+                }
+            }
+        }
+
+        from
+    }
+
+    /// NPCs from the structures of generated chunks, chunks are marked as changed
+    /// to not spawn them again after loading.
+    /// Spawns are sorted by position because chunks are stored in a `HashMap`
+    /// and the order of spawning decides unit ids and RNG draws.
+    pub fn take_spawns(&mut self) -> Vec<(Point, NpcSpawn)> {
+        let mut spawns = Vec::new();
+        for (pos, chunk) in &mut self.chunks {
+            if !chunk.spawns.is_empty() {
+                self.changed.insert(*pos);
+                spawns.append(&mut chunk.spawns);
+            }
+        }
+        // stable sort keeps the order of spawns on the same tile
+        spawns.sort_by_key(|(point, _)| (point.y, point.x));
+
+        spawns
+    }
+
+    /// Only tiles of loaded chunks can be remembered,
+    /// returns `true` if it is the first remembered tile of the chunk
+    pub fn remember_tile(&mut self, pos: Point) -> bool {
+        let (chunk_pos, index) = pos.to_chunk();
+        let Some(chunk) = self.chunks.get_mut(&chunk_pos) else {
+            return false;
+        };
+        let memory = self.memory.entry(chunk_pos).or_default();
+        let discovered = memory.is_empty();
+        memory.remember(index, &chunk.tiles[index]);
+        discovered
+    }
+
+    pub fn remembered(&self, pos: Point) -> Option<&TileMemory> {
+        let (chunk_pos, index) = pos.to_chunk();
+        self.memory.get(&chunk_pos)?.get(index)
+    }
+
     pub fn load_tiles_between(&mut self, left_top: Point, right_bottom: Point) {
         let (ChunkPos { x: lt_x, y: lt_y }, _) = left_top.to_chunk();
         let (ChunkPos { x: rb_x, y: rb_y }, _) = right_bottom.to_chunk();
@@ -65,6 +139,35 @@ impl Map {
         }
     }
 
+    /// Terrains and items emitting light, only loaded chunks are checked
+    pub fn light_emitters(&self, left_top: Point, right_bottom: Point) -> Vec<(Point, u8)> {
+        let (ChunkPos { x: lt_x, y: lt_y }, _) = left_top.to_chunk();
+        let (ChunkPos { x: rb_x, y: rb_y }, _) = right_bottom.to_chunk();
+
+        let mut emitters = Vec::new();
+        for x in lt_x..=rb_x {
+            for y in lt_y..=rb_y {
+                let chunk_pos = ChunkPos::new(x, y);
+                let Some(chunk) = self.chunks.get(&chunk_pos) else {
+                    continue;
+                };
+                for (i, tile) in chunk.tiles.iter().enumerate() {
+                    let light = tile
+                        .items
+                        .iter()
+                        .map(Item::light)
+                        .chain([tile.terrain.light()])
+                        .max()
+                        .unwrap_or(0);
+                    if light > 0 {
+                        emitters.push((TilePos::from_chunk(chunk_pos, i), light));
+                    }
+                }
+            }
+        }
+        emitters
+    }
+
     pub fn tiles_between(&self, left_top: Point, right_bottom: Point) -> Vec<(Point, &Tile)> {
         let (ChunkPos { x: lt_x, y: lt_y }, _) = left_top.to_chunk();
         let (ChunkPos { x: rb_x, y: rb_y }, _) = right_bottom.to_chunk();
@@ -1,7 +1,7 @@
 use serde::Deserialize;
 
-// TODO: why?
-#[derive(Deserialize, Debug)]
+// deserialized in terrain prototypes
+#[derive(Deserialize, Debug, Copy, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum Passage {
     Passable(f32),
@@ -0,0 +1,172 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::f32::consts::SQRT_2;
+
+use geometry::{Direction, Point, DIR8};
+
+use super::{Map, Passage, TerrainInteract};
+
+/// Pass time of the fastest terrain, used for estimating remaining cost
+const MIN_PASS_TIME: f32 = 10.0;
+/// Stop searching after this amount of tiles to not generate half of the world
+const MAX_VISITED: usize = 4096;
+
+#[derive(Eq, PartialEq)]
+struct Node {
+    estimate: u32,
+    cost: u32,
+    pos: Point,
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed to make BinaryHeap pop the cheapest node
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn is_diagonal(dir: Direction) -> bool {
+    matches!(
+        dir,
+        Direction::NorthEast | Direction::SouthEast | Direction::SouthWest | Direction::NorthWest
+    )
+}
+
+/// In tenths of tick to not lose precision on diagonal steps
+fn step_cost(pass_time: f32, dir: Direction) -> u32 {
+    let k_diagonal = if is_diagonal(dir) { SQRT_2 } else { 1.0 };
+    (pass_time * k_diagonal * 10.0).round() as u32
+}
+
+fn heuristic(from: Point, to: Point) -> u32 {
+    let dx = (from.x - to.x).abs();
+    let dy = (from.y - to.y).abs();
+    let diagonal = dx.min(dy);
+    let straight = dx.max(dy) - diagonal;
+    ((straight as f32 + diagonal as f32 * SQRT_2) * MIN_PASS_TIME * 10.0) as u32
+}
+
+/// A* search of the fastest way, tiles with units are blocked.
+/// Returns steps to walk from `from` to `to` or `None` if there is no way.
+pub fn find_path(map: &mut Map, from: Point, to: Point) -> Option<Vec<Direction>> {
+    let mut open = BinaryHeap::from([Node {
+        estimate: heuristic(from, to),
+        cost: 0,
+        pos: from,
+    }]);
+    let mut costs = HashMap::from([(from, 0)]);
+    let mut came_from: HashMap<Point, (Point, Direction)> = HashMap::new();
+
+    while let Some(Node { cost, pos, .. }) = open.pop() {
+        if pos == to {
+            let mut path = Vec::new();
+            let mut current = to;
+            while let Some(&(prev, dir)) = came_from.get(&current) {
+                path.push(dir);
+                current = prev;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        if costs.len() > MAX_VISITED {
+            return None;
+        }
+        if costs.get(&pos).map_or(false, |&c| cost > c) {
+            // already found a faster way to this tile
+            continue;
+        }
+
+        for dir in DIR8.iter().copied() {
+            let next = pos + dir;
+            let tile = map.get_tile(next);
+            let pass_time = match tile.terrain.passage() {
+                Passage::Passable(pass_time) if tile.units.is_empty() => pass_time,
+                _ => continue,
+            };
+            let next_cost = cost + step_cost(pass_time, dir);
+            if costs.get(&next).map_or(true, |&c| next_cost < c) {
+                costs.insert(next, next_cost);
+                came_from.insert(next, (pos, dir));
+                open.push(Node {
+                    estimate: next_cost + heuristic(next, to),
+                    cost: next_cost,
+                    pos: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::{Direction, Point, DIR8};
+
+    use super::{
+        super::{
+            super::{
+                world::tests::{add_npc, prepare_world},
+                World,
+            },
+            terrains::{Dirt, Pit},
+        },
+        find_path,
+    };
+
+    fn prepare_field(world: &World) {
+        let mut map = world.map();
+        for x in -10..=10 {
+            for y in -10..=10 {
+                map.get_tile_mut(Point::new(x, y)).terrain = Dirt::default().into();
+            }
+        }
+    }
+
+    #[test]
+    fn test_straight_path() {
+        let world = prepare_world();
+        prepare_field(&world);
+
+        let path = find_path(&mut world.map(), Point::new(0, 0), Point::new(5, 0)).unwrap();
+        assert_eq!(vec![Direction::East; 5], path);
+    }
+
+    #[test]
+    fn test_path_around_obstacles() {
+        let mut world = prepare_world();
+        prepare_field(&world);
+        for y in -2..=2 {
+            world.map().get_tile_mut(Point::new(2, y)).terrain = Pit::default().into();
+        }
+        add_npc(&mut world, Point::new(1, 3));
+
+        let from = Point::new(0, 0);
+        let to = Point::new(4, 0);
+        let path = find_path(&mut world.map(), from, to).unwrap();
+        let mut pos = from;
+        for dir in path {
+            pos += dir;
+            assert!(pos.x != 2 || pos.y.abs() > 2);
+            assert_ne!(Point::new(1, 3), pos);
+        }
+        assert_eq!(to, pos);
+    }
+
+    #[test]
+    fn test_no_path() {
+        let world = prepare_world();
+        prepare_field(&world);
+        for dir in DIR8.iter().copied() {
+            world.map().get_tile_mut(Point::new(5, 5) + dir).terrain = Pit::default().into();
+        }
+
+        assert!(find_path(&mut world.map(), Point::new(0, 0), Point::new(5, 5)).is_none());
+    }
+}
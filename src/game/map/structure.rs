@@ -0,0 +1,157 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use geometry::Point;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::game::{
+    game_data::{NpcSpawn, StructurePrototype},
+    GameData,
+};
+
+use super::{Biome, Chunk, ChunkPos, Item, Terrain, TerrainInteract, Tile, TilePos};
+
+/// Structure with its north-west corner in some chunk, it may cross the chunk borders
+struct Placement {
+    proto: &'static StructurePrototype,
+    left_top: Point,
+}
+
+/// Every chunk has its own rng for structures, so neighbours can find out what crosses their borders
+fn placement(world_seed: &str, noise_seed: u64, pos: ChunkPos) -> Option<Placement> {
+    let mut hasher = DefaultHasher::new();
+    (world_seed, pos, "structures").hash(&mut hasher);
+    let mut rng = StdRng::seed_from_u64(hasher.finish());
+
+    let structures = &GameData::instance().structures;
+    // HashMap order is random, so sort to keep generation reproducible
+    let mut ids: Vec<&String> = structures.keys().collect();
+    ids.sort();
+    let proto = ids
+        .into_iter()
+        .map(|id| &structures[id])
+        .find(|proto| rng.gen_bool(proto.chance))?;
+    let left_top =
+        pos.left_top() + Point::new(rng.gen_range(0..Chunk::SIZE), rng.gen_range(0..Chunk::SIZE));
+    let center = left_top + Point::new(proto.width() / 2, proto.height() / 2);
+
+    proto
+        .biomes
+        .contains(&Biome::at(noise_seed, center))
+        .then_some(Placement { proto, left_top })
+}
+
+/// Stamps structures placed in this chunk or crossing its borders from the neighbours.
+/// Structures are not bigger than a chunk, so only western and northern neighbours matter.
+pub fn stamp_structures(
+    tiles: &mut [Tile],
+    spawns: &mut Vec<(Point, NpcSpawn)>,
+    world_seed: &str,
+    noise_seed: u64,
+    pos: ChunkPos,
+) {
+    let game_data = GameData::instance();
+    for (dx, dy) in [(-1, -1), (-1, 0), (0, -1), (0, 0)] {
+        let Some(Placement { proto, left_top }) = placement(
+            world_seed,
+            noise_seed,
+            ChunkPos::new(pos.x + dx, pos.y + dy),
+        ) else {
+            continue;
+        };
+        for y in 0..proto.height() {
+            for x in 0..proto.width() {
+                let Some(structure_tile) = proto.tile(x, y) else {
+                    continue;
+                };
+                let point = left_top + Point::new(x, y);
+                let (chunk, index) = point.to_chunk();
+                if chunk != pos {
+                    continue;
+                }
+
+                let tile = &mut tiles[index];
+                // structures with unknown terrains are dropped when the game data is loaded
+                if let Some(terrain) = structure_tile.terrain.as_deref().and_then(Terrain::from_id)
+                {
+                    tile.terrain = terrain;
+                }
+                if !tile.terrain.can_stock_items() {
+                    tile.items.clear();
+                }
+                tile.items.extend(
+                    structure_tile
+                        .items
+                        .iter()
+                        .filter_map(|id| game_data.items.get(id).cloned().map(Item::new)),
+                );
+                if let Some(npc) = &structure_tile.npc {
+                    spawns.push((point, npc.clone()));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::Point;
+
+    use crate::game::GameData;
+
+    use super::{
+        super::{biome::noise_seed, Chunk, ChunkPos, Terrain, TerrainView, TilePos},
+        placement, Placement,
+    };
+
+    #[test]
+    fn test_structures_fit_in_chunk() {
+        for proto in GameData::instance().structures.values() {
+            assert!(proto.width() <= Chunk::SIZE, "{} is too wide", proto.id);
+            assert!(proto.height() <= Chunk::SIZE, "{} is too high", proto.id);
+            for tile in proto.legend.values() {
+                if let Some(terrain) = &tile.terrain {
+                    assert!(
+                        Terrain::from_id(terrain).is_some(),
+                        "{} has unknown terrain {terrain}",
+                        proto.id
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_structure_crossing_chunk_border() {
+        let noise_seed = noise_seed("test");
+        let (pos, Placement { proto, left_top }) = (0..1000)
+            .map(|x| ChunkPos::new(x, 0))
+            .find_map(|pos| {
+                placement("test", noise_seed, pos)
+                    .filter(|p| p.left_top.x + p.proto.width() > pos.right_bottom().x + 1)
+                    .map(|p| (pos, p))
+            })
+            .unwrap();
+        let neighbour = ChunkPos::new(pos.x + 1, pos.y);
+        let chunk = Chunk::generate("test", neighbour);
+        let mut checked = 0;
+        for y in 0..proto.height() {
+            for x in 0..proto.width() {
+                let point = left_top + Point::new(x, y);
+                let (chunk_pos, index) = point.to_chunk();
+                let Some(terrain) = proto.tile(x, y).and_then(|t| t.terrain.as_ref()) else {
+                    continue;
+                };
+                if chunk_pos != neighbour || terrain == "dirt" {
+                    continue;
+                }
+                assert_eq!(
+                    Terrain::from_id(terrain).unwrap().name(),
+                    chunk.tiles[index].terrain.name()
+                );
+                checked += 1;
+            }
+        }
+        assert!(checked > 0);
+    }
+}
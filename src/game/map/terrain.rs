@@ -1,12 +1,14 @@
 use enum_dispatch::enum_dispatch;
 use serde::{Deserialize, Serialize};
 
+use crate::game::{GameData, ItemQuality};
+
 use super::{
-    terrains::{Boulder, Dirt, Grass, Pit, Tree},
+    terrains::{Boulder, Custom, Dirt, Grass, Pit, Tree},
     Item, Passage,
 };
 
-// TODO: JSON-ize all terrains
+// TODO: JSON-ize built-in terrains
 
 #[enum_dispatch]
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -17,6 +19,21 @@ pub enum Terrain {
     Boulder,
     Pit,
     Tree,
+    Custom,
+}
+
+impl Terrain {
+    /// Terrain from JSON prototype or one of built-in "dirt" and "pit"
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "dirt" => Some(Dirt::default().into()),
+            "pit" => Some(Pit::new().into()),
+            _ => GameData::instance()
+                .terrains
+                .contains_key(id)
+                .then(|| Custom::new(id, 0).into()),
+        }
+    }
 }
 
 #[enum_dispatch(Terrain)]
@@ -25,6 +42,10 @@ pub trait TerrainView {
     // TODO: probably use String
     fn looks_like(&self) -> &'static str;
     fn is_transparent(&self) -> bool; // for FOV
+    /// Radius of emitted light
+    fn light(&self) -> u8 {
+        0
+    }
 }
 
 #[enum_dispatch(Terrain)]
@@ -37,9 +58,13 @@ pub trait TerrainInteract {
     fn is_diggable(&self) -> bool {
         false
     }
-    /// return new Terrain and digged items
-    fn dig_result(&self) -> (Terrain, Vec<Item>) {
-        unimplemented!()
+    /// return new Terrain and digged items, `None` if nothing happens
+    fn dig_result(&self) -> Option<(Terrain, Vec<Item>)> {
+        None
+    }
+    /// Items buried here, they are left in the pit after digging
+    fn take_buried(&mut self) -> Vec<Item> {
+        Vec::new()
     }
     fn is_readable(&self) -> bool {
         false
@@ -49,12 +74,40 @@ pub trait TerrainInteract {
     }
     /// Can put items on this tile
     fn can_stock_items(&self) -> bool;
+    /// Should be swum through, items dropped here sink
+    fn is_deep_water(&self) -> bool {
+        false
+    }
+    /// Partially hides units behind it from projectiles
+    fn is_cover(&self) -> bool {
+        false
+    }
+    /// Impassable, but one can climb on it
+    fn is_climbable(&self) -> bool {
+        false
+    }
+    /// Tool quality needed to break this terrain into resources
+    fn breaking_quality(&self) -> Option<ItemQuality> {
+        None
+    }
+    /// Hits left before terrain breaks
+    fn hit_points(&self) -> u8 {
+        0
+    }
+    fn damage(&mut self, _damage: u8) {}
+    /// return new Terrain and dropped resources, `None` if nothing happens
+    fn break_result(&self) -> Option<(Terrain, Vec<Item>)> {
+        None
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        super::terrains::{Dirt, DirtVariant, Grass, GrassVariant},
+        super::{
+            terrains::{Custom, Dirt, DirtVariant, Grass, GrassVariant},
+            Passage,
+        },
         Terrain, TerrainInteract, TerrainView,
     };
 
@@ -63,6 +116,8 @@ mod tests {
         let terrain: Terrain = Dirt::new(DirtVariant::Flat).into();
         assert_eq!("flat dirt", terrain.name());
         assert!(terrain.is_diggable());
+        // nothing happens instead of a panic
+        assert!(terrain.break_result().is_none());
     }
 
     #[test]
@@ -76,4 +131,29 @@ mod tests {
         }
         assert_eq!("dead grass", terrain.name());
     }
+
+    #[test]
+    fn test_custom_terrain() {
+        let terrain: Terrain = Custom::new("mound", 1).into();
+        assert_eq!("mound", terrain.name());
+        assert_eq!("dirt2", terrain.looks_like());
+        assert!(matches!(terrain.passage(), Passage::Passable(..)));
+        assert!(terrain.is_diggable());
+        assert!(matches!(terrain.dig_result().unwrap().0, Terrain::Pit(..)));
+
+        let terrain = Terrain::from_id("gravestone").unwrap();
+        assert!(!terrain.is_passable());
+        assert!(terrain.is_readable());
+        assert!(Terrain::from_id("unknown").is_none());
+    }
+
+    #[test]
+    fn test_old_terrains_still_load() {
+        let terrain: Terrain = serde_json::from_str(r#"{"dirt":{"v":"1"}}"#).unwrap();
+        assert_eq!("flat dirt", terrain.name());
+
+        let json = serde_json::to_string(&Terrain::from(Custom::new("mound", 0))).unwrap();
+        let terrain: Terrain = serde_json::from_str(&json).unwrap();
+        assert_eq!("mound", terrain.name());
+    }
 }
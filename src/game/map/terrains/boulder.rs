@@ -3,18 +3,33 @@ use rand::{
     Rng,
 };
 
-use super::super::{Passage, TerrainInteract, TerrainView};
+use crate::game::ItemQuality;
+
+use super::{
+    super::{items::helpers::stone, Item, Passage, Terrain, TerrainInteract, TerrainView},
+    Custom,
+};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct Boulder {
     #[serde(rename = "s")]
     size: BoulderSize,
-    // TODO: hp, stone type
+    #[serde(rename = "d", default)]
+    damage: u8,
+    // TODO: stone type
 }
 
 impl Boulder {
     pub fn new(size: BoulderSize) -> Self {
-        Self { size }
+        Self { size, damage: 0 }
+    }
+
+    fn max_hit_points(&self) -> u8 {
+        match self.size {
+            BoulderSize::Huge => 120,
+            BoulderSize::Middle => 80,
+            BoulderSize::Small => 40,
+        }
     }
 }
 
@@ -51,6 +66,41 @@ impl TerrainInteract for Boulder {
     fn can_stock_items(&self) -> bool {
         false
     }
+
+    fn is_cover(&self) -> bool {
+        true
+    }
+
+    fn is_climbable(&self) -> bool {
+        matches!(self.size, BoulderSize::Huge | BoulderSize::Middle)
+    }
+
+    fn breaking_quality(&self) -> Option<ItemQuality> {
+        Some(ItemQuality::Smash)
+    }
+
+    fn hit_points(&self) -> u8 {
+        self.max_hit_points().saturating_sub(self.damage)
+    }
+
+    fn damage(&mut self, damage: u8) {
+        self.damage = self
+            .damage
+            .saturating_add(damage)
+            .min(self.max_hit_points());
+    }
+
+    fn break_result(&self) -> Option<(Terrain, Vec<Item>)> {
+        let stones = match self.size {
+            BoulderSize::Huge => 6,
+            BoulderSize::Middle => 4,
+            BoulderSize::Small => 2,
+        };
+        Some((
+            Custom::new("rubble", 0).into(),
+            (0..stones).map(|_| stone()).collect(),
+        ))
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
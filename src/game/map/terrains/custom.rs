@@ -0,0 +1,102 @@
+use rand::Rng;
+
+use crate::game::{game_data::TerrainPrototype, GameData};
+
+use super::{
+    super::{items::Item, Passage, Terrain, TerrainInteract, TerrainView},
+    Dirt,
+};
+
+/// Terrain described by prototype from JSON
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct Custom {
+    #[serde(rename = "i")]
+    id: String,
+    #[serde(rename = "v")]
+    variant: usize,
+}
+
+impl Custom {
+    pub fn new(id: impl Into<String>, variant: usize) -> Self {
+        Self {
+            id: id.into(),
+            variant,
+        }
+    }
+
+    pub fn random<R: Rng + ?Sized>(id: impl Into<String>, rng: &mut R) -> Self {
+        let mut terrain = Self::new(id, 0);
+        terrain.variant = rng.gen_range(0..terrain.proto().looks_like.len().max(1));
+        terrain
+    }
+
+    pub fn proto(&self) -> &'static TerrainPrototype {
+        GameData::instance().terrain(&self.id)
+    }
+}
+
+impl TerrainView for Custom {
+    fn name(&self) -> &str {
+        &self.proto().name
+    }
+
+    fn looks_like(&self) -> &'static str {
+        let looks_like = &self.proto().looks_like;
+        looks_like
+            .get(self.variant)
+            .or_else(|| looks_like.first())
+            .map_or("", String::as_str)
+    }
+
+    fn is_transparent(&self) -> bool {
+        self.proto().is_transparent
+    }
+
+    fn light(&self) -> u8 {
+        self.proto().light
+    }
+}
+
+impl TerrainInteract for Custom {
+    fn passage(&self) -> Passage {
+        self.proto().passage
+    }
+
+    fn is_diggable(&self) -> bool {
+        self.proto().dig_result.is_some()
+    }
+
+    fn dig_result(&self) -> Option<(Terrain, Vec<Item>)> {
+        let dig_result = self.proto().dig_result.as_ref()?;
+        let game_data = GameData::instance();
+        let items = dig_result
+            .items
+            .iter()
+            .filter_map(|id| game_data.items.get(id).cloned().map(Item::new))
+            .collect();
+        // unknown ids are reported when the game data is loaded
+        let terrain =
+            Terrain::from_id(&dig_result.terrain).unwrap_or_else(|| Dirt::default().into());
+        Some((terrain, items))
+    }
+
+    fn is_readable(&self) -> bool {
+        self.proto().read.is_some()
+    }
+
+    fn read(&self) -> String {
+        self.proto().read.clone().unwrap_or_default()
+    }
+
+    fn can_stock_items(&self) -> bool {
+        self.proto().can_stock_items
+    }
+
+    fn is_deep_water(&self) -> bool {
+        self.proto().is_deep_water
+    }
+
+    fn is_climbable(&self) -> bool {
+        self.proto().is_climbable
+    }
+}
@@ -3,17 +3,51 @@ use rand::{
     Rng,
 };
 
-use super::super::{terrains::Pit, Item, Passage, Terrain, TerrainInteract, TerrainView};
+use super::super::{
+    items::helpers::soil, terrains::Pit, Item, Passage, Terrain, TerrainInteract, TerrainView,
+};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct Dirt {
     #[serde(rename = "v")]
     variant: DirtVariant,
+    /// Items hidden in filled pit, they are dug out again
+    #[serde(rename = "b", default, skip_serializing_if = "Vec::is_empty")]
+    buried: Vec<Item>,
+    /// Rain turns dirt into mud
+    #[serde(rename = "m", default, skip_serializing_if = "std::ops::Not::not")]
+    muddy: bool,
 }
 
 impl Dirt {
     pub fn new(variant: DirtVariant) -> Self {
-        Self { variant }
+        Self {
+            variant,
+            buried: Vec::new(),
+            muddy: false,
+        }
+    }
+
+    /// Filled pit with some items in it
+    pub fn filled(buried: Vec<Item>) -> Self {
+        Self {
+            variant: DirtVariant::LotOfChunks,
+            buried,
+            muddy: false,
+        }
+    }
+
+    /// Dirt is trampled flat, buried items stay in place
+    pub fn flatten(&mut self) {
+        self.variant = DirtVariant::Flat;
+    }
+
+    pub fn soak(&mut self) {
+        self.muddy = true;
+    }
+
+    pub fn dry(&mut self) {
+        self.muddy = false;
     }
 }
 
@@ -25,6 +59,9 @@ impl Default for Dirt {
 
 impl TerrainView for Dirt {
     fn name(&self) -> &str {
+        if self.muddy {
+            return "mud";
+        }
         match self.variant {
             DirtVariant::Flat => "flat dirt",
             _ => "dirt",
@@ -32,6 +69,9 @@ impl TerrainView for Dirt {
     }
 
     fn looks_like(&self) -> &'static str {
+        if self.muddy {
+            return "mud";
+        }
         match self.variant {
             DirtVariant::LotOfChunks => "dirt1",
             DirtVariant::SomeChunks => "dirt2",
@@ -48,15 +88,23 @@ impl TerrainView for Dirt {
 
 impl TerrainInteract for Dirt {
     fn passage(&self) -> Passage {
-        Passage::Passable(10.0)
+        if self.muddy {
+            Passage::Passable(25.0)
+        } else {
+            Passage::Passable(10.0)
+        }
     }
 
     fn is_diggable(&self) -> bool {
         true
     }
 
-    fn dig_result(&self) -> (Terrain, Vec<Item>) {
-        (Pit::new().into(), vec![])
+    fn dig_result(&self) -> Option<(Terrain, Vec<Item>)> {
+        Some((Pit::new().into(), vec![soil()]))
+    }
+
+    fn take_buried(&mut self) -> Vec<Item> {
+        self.buried.drain(..).collect()
     }
 
     fn can_stock_items(&self) -> bool {
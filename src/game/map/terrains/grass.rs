@@ -3,17 +3,32 @@ use rand::{
     Rng,
 };
 
-use super::super::{terrains::Pit, Item, Passage, Terrain, TerrainInteract, TerrainView};
+use super::super::{
+    items::helpers::soil, terrains::Pit, Item, Passage, Terrain, TerrainInteract, TerrainView,
+};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct Grass {
     #[serde(rename = "v")]
     variant: GrassVariant,
+    #[serde(rename = "s", default, skip_serializing_if = "std::ops::Not::not")]
+    snowy: bool,
 }
 
 impl Grass {
     pub fn new(variant: GrassVariant) -> Self {
-        Self { variant }
+        Self {
+            variant,
+            snowy: false,
+        }
+    }
+
+    pub fn cover_with_snow(&mut self) {
+        self.snowy = true;
+    }
+
+    pub fn melt(&mut self) {
+        self.snowy = false;
     }
 
     pub fn dead(&self) -> bool {
@@ -59,7 +74,9 @@ impl Grass {
 
 impl TerrainView for Grass {
     fn name(&self) -> &str {
-        if self.dead() {
+        if self.snowy {
+            "snow-covered grass"
+        } else if self.dead() {
             "dead grass"
         } else {
             "grass"
@@ -67,6 +84,9 @@ impl TerrainView for Grass {
     }
 
     fn looks_like(&self) -> &'static str {
+        if self.snowy {
+            return "snow";
+        }
         match self.variant {
             GrassVariant::Grass1 => "grass1",
             GrassVariant::Grass2 => "grass2",
@@ -106,15 +126,19 @@ impl TerrainView for Grass {
 
 impl TerrainInteract for Grass {
     fn passage(&self) -> Passage {
-        Passage::Passable(11.0)
+        if self.snowy {
+            Passage::Passable(15.0)
+        } else {
+            Passage::Passable(11.0)
+        }
     }
 
     fn is_diggable(&self) -> bool {
         true
     }
 
-    fn dig_result(&self) -> (Terrain, Vec<Item>) {
-        (Pit::new().into(), vec![])
+    fn dig_result(&self) -> Option<(Terrain, Vec<Item>)> {
+        Some((Pit::new().into(), vec![soil()]))
     }
 
     fn can_stock_items(&self) -> bool {
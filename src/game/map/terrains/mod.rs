@@ -1,10 +1,12 @@
 pub use boulder::{Boulder, BoulderSize};
+pub use custom::Custom;
 pub use dirt::{Dirt, DirtVariant};
 pub use grass::{Grass, GrassVariant};
 pub use pit::Pit;
 pub use tree::{Tree, TreeVariant};
 
 mod boulder;
+mod custom;
 mod dirt;
 mod grass;
 mod pit;
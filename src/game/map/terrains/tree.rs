@@ -3,18 +3,29 @@ use rand::{
     Rng,
 };
 
-use super::super::{Passage, TerrainInteract, TerrainView};
+use crate::game::ItemQuality;
+
+use super::{
+    super::{
+        items::helpers::{branch, log},
+        Item, Passage, Terrain, TerrainInteract, TerrainView,
+    },
+    Custom,
+};
+
+const TREE_HIT_POINTS: u8 = 60;
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct Tree {
     #[serde(rename = "v")]
     variant: TreeVariant,
-    // TODO: hp
+    #[serde(rename = "d", default)]
+    damage: u8,
 }
 
 impl Tree {
     pub fn new(variant: TreeVariant) -> Self {
-        Self { variant }
+        Self { variant, damage: 0 }
     }
 }
 
@@ -44,6 +55,29 @@ impl TerrainInteract for Tree {
     fn can_stock_items(&self) -> bool {
         false
     }
+
+    fn is_climbable(&self) -> bool {
+        true
+    }
+
+    fn breaking_quality(&self) -> Option<ItemQuality> {
+        Some(ItemQuality::Butch)
+    }
+
+    fn hit_points(&self) -> u8 {
+        TREE_HIT_POINTS.saturating_sub(self.damage)
+    }
+
+    fn damage(&mut self, damage: u8) {
+        self.damage = self.damage.saturating_add(damage).min(TREE_HIT_POINTS);
+    }
+
+    fn break_result(&self) -> Option<(Terrain, Vec<Item>)> {
+        Some((
+            Custom::new("stump", 0).into(),
+            vec![log(), log(), branch(), branch(), branch()],
+        ))
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
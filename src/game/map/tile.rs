@@ -4,12 +4,17 @@ use std::collections::HashSet;
 
 use rand::Rng;
 
+use crate::game::weather::Weather;
+
 use super::{
     items::Item,
     terrain::{Terrain, TerrainInteract, TerrainView},
     terrains::{Dirt, DirtVariant},
 };
 
+/// Part of tiles changed by every weather pass
+const WEATHER_EFFECT_CHANCE: f64 = 0.25;
+
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct Tile {
     #[serde(rename = "t")]
@@ -36,34 +41,71 @@ impl Tile {
     }
 
     /// Calls when avatar walks on tile
-    pub fn on_step(&mut self, unit_id: usize) {
+    pub fn on_step<R: Rng + ?Sized>(&mut self, unit_id: usize, rng: &mut R) {
         self.units.insert(unit_id);
         // TODO: (for future) footprints
-        if rand::thread_rng().gen_bool(0.1) {
+        if rng.gen_bool(0.1) {
             match self.terrain {
                 Terrain::Grass(..) => {
-                    self.terrain = Dirt::new(rand::random::<DirtVariant>()).into();
+                    self.terrain = Dirt::new(rng.gen::<DirtVariant>()).into();
                 }
-                Terrain::Dirt(..) => {
-                    self.terrain = Dirt::new(DirtVariant::Flat).into();
+                Terrain::Dirt(ref mut dirt) => {
+                    dirt.flatten();
                 }
                 _ => {}
             }
         }
     }
 
+    /// Rain makes mud and snow covers grass, clear sky dries and melts them slowly
+    pub fn apply_weather<R: Rng + ?Sized>(&mut self, weather: Weather, rng: &mut R) {
+        if !rng.gen_bool(WEATHER_EFFECT_CHANCE) {
+            return;
+        }
+        match (weather, &mut self.terrain) {
+            (Weather::Rain, Terrain::Dirt(dirt)) => dirt.soak(),
+            (Weather::Clear, Terrain::Dirt(dirt)) => dirt.dry(),
+            (Weather::Snow, Terrain::Grass(grass)) => grass.cover_with_snow(),
+            (Weather::Clear | Weather::Rain, Terrain::Grass(grass)) => grass.melt(),
+            _ => {}
+        }
+    }
+
     pub fn kill_grass(&mut self) {
         if let Terrain::Grass(grass) = &mut self.terrain {
             grass.die();
         }
     }
 
+    /// Items fall to the bottom of deep water and are lost
+    pub fn sinks_items(&self) -> bool {
+        self.terrain.is_deep_water()
+    }
+
     pub fn top_item(&self) -> Option<&Item> {
         self.items.last()
     }
 
     pub fn dig(&mut self) -> Vec<Item> {
-        let (terrain, items) = self.terrain.dig_result();
+        let Some((terrain, items)) = self.terrain.dig_result() else {
+            return Vec::new();
+        };
+        let buried = self.terrain.take_buried();
+        self.terrain = terrain;
+        self.items.extend(buried);
+        items
+    }
+
+    /// Turns pit back to dirt, all items in the pit are buried
+    pub fn fill(&mut self) {
+        let buried = self.items.drain(..).collect();
+        self.terrain = Dirt::filled(buried).into();
+    }
+
+    pub fn break_terrain(&mut self) -> Vec<Item> {
+        let Some((terrain, items)) = self.terrain.break_result() else {
+            return Vec::new();
+        };
         self.terrain = terrain;
         items
     }
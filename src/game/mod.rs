@@ -1,17 +1,33 @@
 pub use actions::{Action, ActionType};
 pub use avatar::Avatar;
-pub use game_data::{GameData, ItemPrototype, ItemQuality, ItemSpecial, ItemTag};
+pub use calendar::DateTime;
+pub use game_data::{
+    EdgePrototype, GameData, ItemPrototype, ItemQuality, ItemSpecial, ItemTag, RangedPrototype,
+};
+pub use hands::{Hand, Hands};
 pub use log::Log;
 pub use map::{Chunk, ChunkPos, Item, Map, Terrain, TerrainInteract, TerrainView, Tile, TilePos};
-pub use savage::{CharSheet, Dice, SkillLevel};
+pub use savage::{
+    Advance, Attribute, CharSheet, Dice, SkillLevel, ADVANCE_POINTS, EDGE_COST,
+    MAX_HINDRANCE_POINTS,
+};
+pub use weather::{Weather, WeatherState};
 pub use world::World;
 
 pub mod actions;
+pub mod ai;
 mod avatar;
+mod bennies;
+mod calendar;
+mod dress;
+mod experience;
 mod game_data;
+mod hands;
 mod log;
 pub mod map;
 pub mod races;
 mod savage;
+mod stealth;
 pub mod traits;
+mod weather;
 pub mod world;
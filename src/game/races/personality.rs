@@ -53,15 +53,24 @@ impl Personality {
     }
 
     pub fn random<R: Rng + ?Sized>(rng: &mut R, is_player: bool, alive: bool) -> Personality {
-        let gender = rng.sample(Standard);
-        let sex = Sex::from(&gender);
-        let game_data = GameData::instance();
         let race = if is_player {
             let race: PlayableRace = rng.sample(Standard);
             Race::from(race)
         } else {
             rng.sample(Standard)
         };
+        Self::random_of_race(rng, race, is_player, alive)
+    }
+
+    pub fn random_of_race<R: Rng + ?Sized>(
+        rng: &mut R,
+        race: Race,
+        is_player: bool,
+        alive: bool,
+    ) -> Personality {
+        let gender = rng.sample(Standard);
+        let sex = Sex::from(&gender);
+        let game_data = GameData::instance();
         let name = game_data
             .names
             .get(&race)
@@ -24,6 +24,11 @@ impl Race {
         matches!(self, Race::Gazan | Race::Lagnam)
     }
 
+    /// Totiks swim faster and better than others
+    pub fn is_aquatic(self) -> bool {
+        matches!(self, Race::Totik)
+    }
+
     pub fn free_skills(self) -> HashMap<Skill, SkillLevel> {
         HashMap::from_iter(match self {
             Race::Gazan => vec![(Skill::Climbing, SkillLevel::D6)],
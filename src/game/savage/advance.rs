@@ -0,0 +1,17 @@
+use super::{Attribute, Skill};
+
+/// Experience needed for every Advance
+pub const EXPERIENCE_PER_ADVANCE: u32 = 20;
+/// Points one Advance gives, raising an attribute or taking an Edge costs all of them
+pub const ADVANCE_POINTS: u8 = 2;
+
+/// Ways to spend Advance points
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Advance {
+    /// One step, costs [`ADVANCE_POINTS`]
+    Attribute(Attribute),
+    /// One step, costs as many points as the same step during the character creation
+    Skill(Skill),
+    /// Id of the Edge from the game data, costs [`ADVANCE_POINTS`]
+    Edge(&'static str),
+}
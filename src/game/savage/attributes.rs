@@ -2,6 +2,8 @@ use rand::distributions::Standard;
 use rand::prelude::Distribution;
 use rand::Rng;
 
+use crate::game::traits::Name;
+
 use super::Dice;
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
@@ -19,11 +21,11 @@ pub struct Attributes {
 }
 
 impl Attributes {
-    pub fn random() -> Self {
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
         let mut attributes = Self::default();
         let mut points = 5;
         while points > 0 {
-            let random_attr = rand::thread_rng().gen::<Attribute>();
+            let random_attr = rng.gen::<Attribute>();
             attributes.set_attribute(random_attr, attributes.get_attribute(random_attr) + 1);
             points -= 1;
         }
@@ -64,7 +66,8 @@ impl Default for Attributes {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(serde::Deserialize, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
 pub enum Attribute {
     Agility,
     Smarts,
@@ -73,6 +76,24 @@ pub enum Attribute {
     Vigor,
 }
 
+impl From<Attribute> for &str {
+    fn from(attribute: Attribute) -> Self {
+        match attribute {
+            Attribute::Agility => "Agility",
+            Attribute::Smarts => "Smarts",
+            Attribute::Spirit => "Spirit",
+            Attribute::Strength => "Strength",
+            Attribute::Vigor => "Vigor",
+        }
+    }
+}
+
+impl Name for Attribute {
+    fn name(&self) -> &'static str {
+        (*self).into()
+    }
+}
+
 impl Distribution<Attribute> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Attribute {
         match rng.gen::<u8>() % 5 {
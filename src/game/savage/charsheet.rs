@@ -1,12 +1,62 @@
+use rand::Rng;
+
+use crate::game::game_data::{Effect, GameData};
 use crate::game::races::Race;
 use crate::game::{Dice, SkillLevel};
 
-use super::{Attributes, Skills};
+use crate::game::traits::Name;
+
+use super::{
+    Advance, Attribute, Attributes, Modifiers, Skill, Skills, TraitRoll, ADVANCE_POINTS,
+    EXPERIENCE_PER_ADVANCE,
+};
+
+/// Unit is Incapacitated after getting more wounds than this
+pub const MAX_WOUNDS: u8 = 3;
+/// Fatigued and Exhausted, next level of Fatigue makes unit Incapacitated
+pub const MAX_FATIGUE: u8 = 2;
+/// Pace of a character without Edges and Hindrances
+pub const BASE_PACE: u8 = 6;
+/// Hindrances give points for Edges, but not more than this
+pub const MAX_HINDRANCE_POINTS: u8 = 4;
+/// Hindrance points one Edge costs during the character creation
+pub const EDGE_COST: u8 = 2;
+/// Skill points to spend during the character creation
+const SKILL_POINTS: i8 = 15;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HitResult {
+    /// Damage is less than Toughness
+    Nothing,
+    Shaken,
+    /// Number of new wounds
+    Wounded(u8),
+    /// Got more wounds than unit can take
+    Incapacitated,
+}
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct CharSheet {
     pub attributes: Attributes,
     pub skills: Skills,
+    #[serde(default)]
+    pub shaken: bool,
+    #[serde(default)]
+    pub wounds: u8,
+    #[serde(default)]
+    pub fatigue: u8,
+    /// Ids of Edges from the game data
+    #[serde(default)]
+    pub edges: Vec<String>,
+    /// Ids of Hindrances from the game data
+    #[serde(default)]
+    pub hindrances: Vec<String>,
+    /// Every [`EXPERIENCE_PER_ADVANCE`] gives an Advance
+    #[serde(default)]
+    pub experience: u32,
+    /// Unspent points of Advances
+    #[serde(default)]
+    pub advance_points: u8,
 }
 
 impl CharSheet {
@@ -14,19 +64,201 @@ impl CharSheet {
         Self {
             attributes: Attributes::default(),
             skills: Skills::default(race),
+            shaken: false,
+            wounds: 0,
+            fatigue: 0,
+            edges: Vec::new(),
+            hindrances: Vec::new(),
+            experience: 0,
+            advance_points: 0,
         }
     }
 
-    pub fn random(race: Race) -> Self {
+    pub fn random<R: Rng + ?Sized>(rng: &mut R, race: Race) -> Self {
         Self {
-            attributes: Attributes::random(),
+            attributes: Attributes::random(rng),
             // TODO: randomize skills
             skills: Skills::default(race),
+            shaken: false,
+            wounds: 0,
+            fatigue: 0,
+            edges: Vec::new(),
+            hindrances: Vec::new(),
+            experience: 0,
+            advance_points: 0,
         }
     }
 
+    /// 2 plus half of Fighting, untrained ones have just 2
+    pub fn parry(&self) -> u8 {
+        match self.skills.fighting {
+            SkillLevel::D4_2 => 2,
+            fighting => 2 + Dice::from(fighting).value() / 2,
+        }
+    }
+
+    /// 2 plus half of Vigor plus armor
+    pub fn toughness(&self, armor: u8) -> u8 {
+        let toughness = 2 + self.attributes.vigor.value() / 2 + armor;
+        toughness.saturating_add_signed(self.effects().map(|(_, e)| e.toughness()).sum())
+    }
+
+    pub fn pace(&self) -> u8 {
+        BASE_PACE
+            .saturating_add_signed(self.effects().map(|(_, e)| e.pace()).sum())
+            .max(1)
+    }
+
+    /// Tiles added to the vision range
+    pub fn vision_bonus(&self) -> i32 {
+        self.effects().map(|(_, e)| i32::from(e.vision())).sum()
+    }
+
+    /// Effects of Edges and Hindrances with their names
+    fn effects(&self) -> impl Iterator<Item = (&'static str, Effect)> + '_ {
+        let data = GameData::instance();
+        let edges = self
+            .edges
+            .iter()
+            .filter_map(|id| data.edges.get(id))
+            .map(|edge| (edge.name.as_str(), &edge.effects));
+        let hindrances = self
+            .hindrances
+            .iter()
+            .filter_map(|id| data.hindrances.get(id))
+            .map(|hindrance| (hindrance.name.as_str(), &hindrance.effects));
+        edges
+            .chain(hindrances)
+            .flat_map(|(name, effects)| effects.iter().map(move |&effect| (name, effect)))
+    }
+
+    /// Hindrance points to spend on Edges, extra Hindrances give nothing
+    pub fn hindrance_points(&self) -> u8 {
+        let data = GameData::instance();
+        self.hindrances
+            .iter()
+            .filter_map(|id| data.hindrances.get(id))
+            .map(|hindrance| hindrance.severity.points())
+            .sum::<u8>()
+            .min(MAX_HINDRANCE_POINTS)
+    }
+
+    /// Edge is not taken yet and its requirements are met
+    pub fn can_take_edge(&self, race: Race, id: &str) -> bool {
+        !self.edges.iter().any(|e| e == id)
+            && GameData::instance()
+                .edges
+                .get(id)
+                .is_some_and(|edge| edge.requirements.are_met(race, self))
+    }
+
+    /// Every wound gives -1 to all trait rolls, but not more than -3
+    pub fn wound_penalty(&self) -> i8 {
+        -(self.wounds.min(MAX_WOUNDS) as i8)
+    }
+
+    /// Every level of Fatigue gives -1 to all trait rolls
+    pub fn fatigue_penalty(&self) -> i8 {
+        -(self.fatigue.min(MAX_FATIGUE) as i8)
+    }
+
+    /// Returns `false` if unit got more Fatigue than it can take
+    pub fn add_fatigue(&mut self) -> bool {
+        if self.fatigue >= MAX_FATIGUE {
+            return false;
+        }
+        self.fatigue += 1;
+        true
+    }
+
+    /// Wounds and Fatigue apply to every trait roll
+    fn with_penalties(&self, modifiers: Modifiers) -> Modifiers {
+        modifiers
+            .with("wounds", self.wound_penalty())
+            .with("fatigue", self.fatigue_penalty())
+    }
+
+    pub fn roll_attribute<R: Rng + ?Sized>(
+        &self,
+        attribute: Attribute,
+        mut modifiers: Modifiers,
+        rng: &mut R,
+    ) -> TraitRoll {
+        for (name, effect) in self.effects() {
+            modifiers = modifiers.with(name, effect.attribute_modifier(attribute));
+        }
+        TraitRoll::roll(
+            attribute.name(),
+            self.attributes.get_attribute(attribute),
+            self.with_penalties(modifiers),
+            rng,
+        )
+    }
+
+    pub fn roll_skill<R: Rng + ?Sized>(
+        &self,
+        skill: Skill,
+        modifiers: Modifiers,
+        rng: &mut R,
+    ) -> TraitRoll {
+        let level = self.skills.get_skill(skill);
+        let mut modifiers = if level == SkillLevel::D4_2 {
+            modifiers.with("untrained", -2)
+        } else {
+            modifiers
+        };
+        for (name, effect) in self.effects() {
+            modifiers = modifiers.with(name, effect.skill_modifier(skill));
+        }
+        TraitRoll::roll(
+            skill.name(),
+            level.into(),
+            self.with_penalties(modifiers),
+            rng,
+        )
+    }
+
+    /// Number of wounds the damage would cause, without applying it
+    pub fn wounds_from(&self, damage: u8, armor: u8) -> u8 {
+        let toughness = self.toughness(armor);
+        if damage < toughness {
+            return 0;
+        }
+        let raises = (damage - toughness) / 4;
+        if raises == 0 && !self.shaken {
+            0
+        } else {
+            // Shaken again without a raise is a wound too
+            raises.max(1)
+        }
+    }
+
+    /// Success makes unit Shaken, every raise causes a wound
+    pub fn apply_damage(&mut self, damage: u8, armor: u8) -> HitResult {
+        if damage < self.toughness(armor) {
+            return HitResult::Nothing;
+        }
+        let wounds = self.wounds_from(damage, armor);
+        if wounds == 0 {
+            self.shaken = true;
+            return HitResult::Shaken;
+        }
+        self.shaken = true;
+        if self.wounds + wounds > MAX_WOUNDS {
+            self.wounds = MAX_WOUNDS;
+            return HitResult::Incapacitated;
+        }
+        self.wounds += wounds;
+        HitResult::Wounded(wounds)
+    }
+
     pub fn calc_skill_points(&self, race: Race) -> u8 {
-        let mut skill_points = 15;
+        (SKILL_POINTS - self.spent_skill_points(race)).max(0) as u8
+    }
+
+    /// Skill steps above the race's free skills, steps above the linked attribute cost double
+    fn spent_skill_points(&self, race: Race) -> i8 {
+        let mut spent = 0;
         for (attr, skill, value) in self.skills.get_skills_by_attributes() {
             let mut attr_value = self.attributes.get_attribute(attr);
             let mut base_value = SkillLevel::D4_2;
@@ -37,10 +269,241 @@ impl CharSheet {
                 attr_value = Dice::max(free_skill_level.into(), attr_value);
                 base_value = free_skill_level;
             }
-            skill_points -=
-                (value as i8 - base_value as i8) + value.steps_above_attr(attr_value).max(0);
+            spent += (value as i8 - base_value as i8) + value.steps_above_attr(attr_value).max(0);
+        }
+
+        spent
+    }
+
+    /// Returns number of new Advances
+    pub fn add_experience(&mut self, amount: u32) -> u8 {
+        let before = self.experience / EXPERIENCE_PER_ADVANCE;
+        self.experience += amount;
+        let advances = (self.experience / EXPERIENCE_PER_ADVANCE - before) as u8;
+        self.advance_points = self
+            .advance_points
+            .saturating_add(advances * ADVANCE_POINTS);
+        advances
+    }
+
+    /// Advance points needed, `None` if it is impossible
+    pub fn advance_cost(&self, race: Race, advance: Advance) -> Option<u8> {
+        match advance {
+            Advance::Attribute(attribute) => {
+                (self.attributes.get_attribute(attribute) < Dice::D12).then_some(ADVANCE_POINTS)
+            }
+            Advance::Skill(skill) => {
+                let level = self.skills.get_skill(skill).next()?;
+                let mut raised = self.clone();
+                raised.skills.set_skill(skill, level);
+                Some((raised.spent_skill_points(race) - self.spent_skill_points(race)) as u8)
+            }
+            Advance::Edge(id) => self.can_take_edge(race, id).then_some(ADVANCE_POINTS),
         }
+    }
+
+    /// Spends Advance points, returns `false` if there are not enough
+    pub fn advance(&mut self, race: Race, advance: Advance) -> bool {
+        let Some(cost) = self
+            .advance_cost(race, advance)
+            .filter(|&cost| cost <= self.advance_points)
+        else {
+            return false;
+        };
+        match advance {
+            Advance::Attribute(attribute) => self
+                .attributes
+                .set_attribute(attribute, self.attributes.get_attribute(attribute) + 1),
+            Advance::Skill(skill) => {
+                if let Some(level) = self.skills.get_skill(skill).next() {
+                    self.skills.set_skill(skill, level);
+                }
+            }
+            Advance::Edge(id) => self.edges.push(id.to_string()),
+        }
+        self.advance_points -= cost;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::mock::StepRng;
+
+    use super::{
+        super::super::races::Race, Advance, Attribute, CharSheet, Dice, HitResult, Modifiers,
+        Skill, SkillLevel, ADVANCE_POINTS, BASE_PACE, EXPERIENCE_PER_ADVANCE, MAX_FATIGUE,
+        MAX_HINDRANCE_POINTS,
+    };
+
+    #[test]
+    fn test_parry() {
+        let mut char_sheet = CharSheet::default(Race::Gazan);
+        assert_eq!(2, char_sheet.parry());
+        char_sheet.skills.fighting = SkillLevel::D4;
+        assert_eq!(4, char_sheet.parry());
+        char_sheet.skills.fighting = SkillLevel::D10;
+        assert_eq!(7, char_sheet.parry());
+    }
+
+    #[test]
+    fn test_applying_damage() {
+        let mut char_sheet = CharSheet::default(Race::Gazan);
+        char_sheet.attributes.vigor = Dice::D6;
+        assert_eq!(5, char_sheet.toughness(0));
+        assert_eq!(7, char_sheet.toughness(2));
+
+        assert_eq!(HitResult::Nothing, char_sheet.apply_damage(4, 0));
+        assert!(!char_sheet.shaken);
+        assert_eq!(HitResult::Nothing, char_sheet.apply_damage(5, 1));
+        assert_eq!(HitResult::Shaken, char_sheet.apply_damage(5, 0));
+        assert!(char_sheet.shaken);
+        assert_eq!(0, char_sheet.wounds);
+        assert_eq!(HitResult::Wounded(1), char_sheet.apply_damage(8, 0));
+        assert_eq!(1, char_sheet.wounds);
+        assert_eq!(HitResult::Wounded(2), char_sheet.apply_damage(13, 0));
+        assert_eq!(3, char_sheet.wounds);
+        assert_eq!(HitResult::Incapacitated, char_sheet.apply_damage(5, 0));
+        assert_eq!(3, char_sheet.wounds);
+    }
+
+    #[test]
+    fn test_wound_penalty() {
+        let mut char_sheet = CharSheet::default(Race::Gazan);
+        char_sheet.attributes.spirit = Dice::D6;
+        char_sheet.skills.fighting = SkillLevel::D6;
+        // always rolls 3 on d6
+        let mut rng = StepRng::new(2, 0);
+        let spirit = |char_sheet: &CharSheet, rng: &mut StepRng| {
+            char_sheet
+                .roll_attribute(Attribute::Spirit, Modifiers::default(), rng)
+                .total()
+        };
+        let fighting = |char_sheet: &CharSheet, rng: &mut StepRng| {
+            char_sheet
+                .roll_skill(Skill::Fighting, Modifiers::default(), rng)
+                .total()
+        };
+        assert_eq!(3, spirit(&char_sheet, &mut rng));
+        assert_eq!(3, fighting(&char_sheet, &mut rng));
+
+        char_sheet.wounds = 2;
+        assert_eq!(-2, char_sheet.wound_penalty());
+        assert_eq!(1, spirit(&char_sheet, &mut rng));
+        assert_eq!(1, fighting(&char_sheet, &mut rng));
+        char_sheet.wounds = 5;
+        assert_eq!(-3, char_sheet.wound_penalty());
+    }
+
+    #[test]
+    fn test_wild_die() {
+        let mut char_sheet = CharSheet::default(Race::Gazan);
+        char_sheet.skills.fighting = SkillLevel::D4;
+        let wild_card = Modifiers::default().wild_card(true);
+        // always rolls 1 on d4 and 5 on d6
+        let mut rng = StepRng::new(4, 0);
+        let roll = char_sheet.roll_skill(Skill::Fighting, Modifiers::default(), &mut rng);
+        assert_eq!(1, roll.total());
+        let roll = char_sheet.roll_skill(Skill::Fighting, wild_card.clone(), &mut rng);
+        assert_eq!(5, roll.total());
+        // untrained Wild Die gets -2 too
+        char_sheet.skills.fighting = SkillLevel::D4_2;
+        let roll = char_sheet.roll_skill(Skill::Fighting, wild_card, &mut rng);
+        assert_eq!(3, roll.total());
+        assert_eq!(vec![("untrained", -2)], roll.modifiers);
+    }
+
+    #[test]
+    fn test_edges_and_hindrances() {
+        let mut char_sheet = CharSheet::default(Race::Gazan);
+        char_sheet.skills.notice = SkillLevel::D6;
+        let toughness = char_sheet.toughness(0);
+        char_sheet.edges = vec!["brawny".to_string(), "alertness".to_string()];
+        char_sheet.hindrances = vec!["lame".to_string(), "bad_eyes".to_string()];
+        assert_eq!(toughness + 1, char_sheet.toughness(0));
+        assert_eq!(BASE_PACE - 2, char_sheet.pace());
+        assert_eq!(-16, char_sheet.vision_bonus());
+        let roll =
+            char_sheet.roll_skill(Skill::Notice, Modifiers::default(), &mut StepRng::new(0, 0));
+        assert_eq!(vec![("Alertness", 2), ("Bad Eyes", -1)], roll.modifiers);
+
+        assert_eq!(3, char_sheet.hindrance_points());
+        char_sheet.hindrances.push("clumsy".to_string());
+        assert_eq!(MAX_HINDRANCE_POINTS, char_sheet.hindrance_points());
+
+        assert!(!char_sheet.can_take_edge(Race::Gazan, "brawny"));
+        assert!(!char_sheet.can_take_edge(Race::Gazan, "quick"));
+        char_sheet.attributes.agility = Dice::D8;
+        assert!(char_sheet.can_take_edge(Race::Gazan, "quick"));
+        assert!(char_sheet.can_take_edge(Race::Gazan, "tree_runner"));
+        assert!(!char_sheet.can_take_edge(Race::Bug, "tree_runner"));
+        assert!(!char_sheet.can_take_edge(Race::Gazan, "unknown"));
+    }
+
+    #[test]
+    fn test_advances() {
+        let mut char_sheet = CharSheet::default(Race::Gazan);
+        assert_eq!(0, char_sheet.add_experience(EXPERIENCE_PER_ADVANCE - 1));
+        assert_eq!(0, char_sheet.advance_points);
+        assert_eq!(2, char_sheet.add_experience(EXPERIENCE_PER_ADVANCE + 1));
+        assert_eq!(2 * ADVANCE_POINTS, char_sheet.advance_points);
+
+        // steps up to the attribute cost 1, steps above it cost 2
+        let notice = Advance::Skill(Skill::Notice);
+        assert_eq!(Some(1), char_sheet.advance_cost(Race::Gazan, notice));
+        assert!(char_sheet.advance(Race::Gazan, notice));
+        assert_eq!(SkillLevel::D4, char_sheet.skills.notice);
+        assert_eq!(Some(2), char_sheet.advance_cost(Race::Gazan, notice));
+        // free skill of the race
+        let climbing = Advance::Skill(Skill::Climbing);
+        assert_eq!(Some(2), char_sheet.advance_cost(Race::Gazan, climbing));
+
+        let agility = Advance::Attribute(Attribute::Agility);
+        assert!(char_sheet.advance(Race::Gazan, agility));
+        assert_eq!(Dice::D6, char_sheet.attributes.agility);
+        assert_eq!(1, char_sheet.advance_points);
+        assert!(!char_sheet.advance(Race::Gazan, agility));
+        assert_eq!(Dice::D6, char_sheet.attributes.agility);
+
+        char_sheet.add_experience(EXPERIENCE_PER_ADVANCE);
+        assert_eq!(
+            None,
+            char_sheet.advance_cost(Race::Gazan, Advance::Edge("quick"))
+        );
+        assert!(char_sheet.advance(Race::Gazan, Advance::Edge("alertness")));
+        assert_eq!(vec!["alertness".to_string()], char_sheet.edges);
+        assert!(!char_sheet.advance(Race::Gazan, Advance::Edge("alertness")));
+
+        char_sheet.attributes.strength = Dice::D12;
+        let strength = Advance::Attribute(Attribute::Strength);
+        assert_eq!(None, char_sheet.advance_cost(Race::Gazan, strength));
+    }
+
+    #[test]
+    fn test_wounds_from_damage() {
+        let mut char_sheet = CharSheet::default(Race::Gazan);
+        char_sheet.attributes.vigor = Dice::D6;
+        assert_eq!(0, char_sheet.wounds_from(4, 0));
+        assert_eq!(0, char_sheet.wounds_from(5, 0));
+        assert_eq!(1, char_sheet.wounds_from(9, 0));
+        assert_eq!(2, char_sheet.wounds_from(13, 0));
+        char_sheet.shaken = true;
+        assert_eq!(1, char_sheet.wounds_from(5, 0));
+    }
 
-        skill_points.max(0) as u8
+    #[test]
+    fn test_fatigue() {
+        let mut char_sheet = CharSheet::default(Race::Gazan);
+        char_sheet.attributes.spirit = Dice::D6;
+        // always rolls 3 on d6
+        let mut rng = StepRng::new(2, 0);
+        assert!(char_sheet.add_fatigue());
+        let roll = char_sheet.roll_attribute(Attribute::Spirit, Modifiers::default(), &mut rng);
+        assert_eq!(2, roll.total());
+        assert_eq!(vec![("fatigue", -1)], roll.modifiers);
+        assert!(char_sheet.add_fatigue());
+        assert_eq!(-2, char_sheet.fatigue_penalty());
+        assert!(!char_sheet.add_fatigue());
+        assert_eq!(MAX_FATIGUE, char_sheet.fatigue);
     }
 }
@@ -60,18 +60,18 @@ impl Dice {
         }
     }
 
-    pub fn roll(self) -> u8 {
-        rand::thread_rng().gen::<u8>() % self.value() + 1
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
+        rng.gen::<u8>() % self.value() + 1
     }
 
     /// Roll a dice that explodes on the maximum value.
-    pub fn roll_wild(self) -> u8 {
+    pub fn roll_wild<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
         let mut total = 0u8;
-        let mut roll = self.roll();
+        let mut roll = self.roll(rng);
         while roll == self.value() {
             // probably u8 here is not an intelligent choice but who cares
             total = total.saturating_add(roll);
-            roll = self.roll();
+            roll = self.roll(rng);
         }
         total + roll
     }
@@ -130,12 +130,12 @@ impl DiceWithModifier {
         DiceWithModifier(dice, 0)
     }
 
-    pub fn roll(self) -> u8 {
-        (self.0.roll() as i8 + self.1).max(1) as u8
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
+        (self.0.roll(rng) as i8 + self.1).max(1) as u8
     }
 
-    pub fn roll_wild(self) -> u8 {
-        (self.0.roll_wild() as i8 + self.1).max(1) as u8
+    pub fn roll_wild<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
+        (self.0.roll_wild(rng) as i8 + self.1).max(1) as u8
     }
 }
 
@@ -215,12 +215,12 @@ impl Name for SkillLevel {
 }
 
 impl SkillLevel {
-    pub fn roll(self) -> u8 {
-        DiceWithModifier::from(self).roll()
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
+        DiceWithModifier::from(self).roll(rng)
     }
 
-    pub fn roll_wild(self) -> u8 {
-        DiceWithModifier::from(self).roll_wild()
+    pub fn roll_wild<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
+        DiceWithModifier::from(self).roll_wild(rng)
     }
 
     pub fn next(self) -> Option<Self> {
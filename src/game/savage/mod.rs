@@ -1,9 +1,13 @@
+pub use advance::{Advance, ADVANCE_POINTS, EXPERIENCE_PER_ADVANCE};
 pub use attributes::{Attribute, Attributes};
-pub use charsheet::CharSheet;
-pub use dice::{Dice, DiceWithModifier, SkillLevel};
+pub use charsheet::{CharSheet, HitResult, BASE_PACE, EDGE_COST, MAX_HINDRANCE_POINTS};
+pub use dice::{Dice, SkillLevel};
 pub use skills::{Skill, Skills};
+pub use trait_roll::{Modifiers, TraitRoll};
 
+mod advance;
 mod attributes;
 mod charsheet;
 mod dice;
 mod skills;
+mod trait_roll;
@@ -1,4 +1,5 @@
 use crate::game::races::Race;
+use crate::game::traits::Name;
 
 use super::{Attribute, SkillLevel};
 
@@ -50,7 +51,6 @@ impl Skills {
         }
     }
 
-    #[allow(dead_code)]
     pub fn get_skill(&self, skill: Skill) -> SkillLevel {
         match skill {
             Skill::Athletics => self.athletics,
@@ -112,7 +112,8 @@ impl Skills {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(serde::Deserialize, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
 pub enum Skill {
     Athletics,
     Fighting,
@@ -130,3 +131,31 @@ pub enum Skill {
     Intimidation,
     Climbing,
 }
+
+impl From<Skill> for &str {
+    fn from(skill: Skill) -> Self {
+        match skill {
+            Skill::Athletics => "Athletics",
+            Skill::Fighting => "Fighting",
+            Skill::Shooting => "Shooting",
+            Skill::Stealth => "Stealth",
+            Skill::Thievery => "Thievery",
+            Skill::Swimming => "Swimming",
+            Skill::Gambling => "Gambling",
+            Skill::Notice => "Notice",
+            Skill::Survival => "Survival",
+            Skill::Healing => "Healing",
+            Skill::Repair => "Repair",
+            Skill::Reading => "Reading",
+            Skill::Persuasion => "Persuasion",
+            Skill::Intimidation => "Intimidation",
+            Skill::Climbing => "Climbing",
+        }
+    }
+}
+
+impl Name for Skill {
+    fn name(&self) -> &'static str {
+        (*self).into()
+    }
+}
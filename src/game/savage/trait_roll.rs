@@ -0,0 +1,224 @@
+use std::fmt::Write;
+
+use rand::Rng;
+
+use crate::game::traits::Name;
+
+use super::Dice;
+
+/// Target Number of most trait rolls
+const DEFAULT_TARGET_NUMBER: i16 = 4;
+/// Every 4 points above the Target Number is a raise
+const RAISE: i16 = 4;
+
+/// One die with all its explosions
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DieRoll {
+    pub dice: Dice,
+    /// Die explodes on the maximum value and is rolled again
+    pub rolls: Vec<u8>,
+}
+
+impl DieRoll {
+    pub fn roll<R: Rng + ?Sized>(dice: Dice, rng: &mut R) -> Self {
+        let mut rolls = vec![dice.roll(rng)];
+        while rolls.last() == Some(&dice.value()) {
+            rolls.push(dice.roll(rng));
+        }
+        Self { dice, rolls }
+    }
+
+    pub fn total(&self) -> i16 {
+        self.rolls.iter().map(|&r| i16::from(r)).sum()
+    }
+
+    fn description(&self) -> String {
+        let rolls: Vec<String> = self.rolls.iter().map(u8::to_string).collect();
+        format!("{} [{}]", self.dice.name(), rolls.join("+"))
+    }
+}
+
+/// Situational modifiers of a trait roll and what it is rolled against
+#[derive(Debug, Clone)]
+pub struct Modifiers {
+    list: Vec<(&'static str, i8)>,
+    target: i16,
+    wild_card: bool,
+}
+
+impl Default for Modifiers {
+    fn default() -> Self {
+        Self {
+            list: Vec::new(),
+            target: DEFAULT_TARGET_NUMBER,
+            wild_card: false,
+        }
+    }
+}
+
+impl Modifiers {
+    /// Zero modifiers are skipped, they only clutter the breakdown
+    pub fn with(mut self, name: &'static str, value: i8) -> Self {
+        if value != 0 {
+            self.list.push((name, value));
+        }
+        self
+    }
+
+    /// Target Number, like Parry for attacks or the opponent's total for opposed rolls
+    pub fn against(mut self, target: i16) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Wild Cards roll an extra d6 Wild Die and keep the best
+    pub fn wild_card(mut self, wild_card: bool) -> Self {
+        self.wild_card = wild_card;
+        self
+    }
+}
+
+/// Everything that made the result of a trait roll
+#[derive(Debug, Clone)]
+pub struct TraitRoll {
+    /// Name of the skill or attribute
+    pub name: &'static str,
+    pub trait_die: DieRoll,
+    pub wild_die: Option<DieRoll>,
+    pub modifiers: Vec<(&'static str, i8)>,
+    pub target: i16,
+}
+
+impl TraitRoll {
+    pub fn roll<R: Rng + ?Sized>(
+        name: &'static str,
+        dice: Dice,
+        modifiers: Modifiers,
+        rng: &mut R,
+    ) -> Self {
+        let trait_die = DieRoll::roll(dice, rng);
+        let wild_die = modifiers.wild_card.then(|| DieRoll::roll(Dice::D6, rng));
+        Self {
+            name,
+            trait_die,
+            wild_die,
+            modifiers: modifiers.list,
+            target: modifiers.target,
+        }
+    }
+
+    /// Best of the trait die and the Wild Die
+    pub fn kept_die(&self) -> &DieRoll {
+        match &self.wild_die {
+            Some(wild_die) if wild_die.total() > self.trait_die.total() => wild_die,
+            _ => &self.trait_die,
+        }
+    }
+
+    pub fn modifier(&self) -> i16 {
+        self.modifiers.iter().map(|&(_, m)| i16::from(m)).sum()
+    }
+
+    pub fn total(&self) -> i16 {
+        self.kept_die().total() + self.modifier()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.total() >= self.target
+    }
+
+    pub fn raises(&self) -> u8 {
+        if self.is_success() {
+            ((self.total() - self.target) / RAISE) as u8
+        } else {
+            0
+        }
+    }
+
+    /// Success and every raise, used for soaking wounds and alike
+    pub fn successes(&self) -> u8 {
+        if self.is_success() {
+            1 + self.raises()
+        } else {
+            0
+        }
+    }
+
+    /// Human readable description, like
+    /// `Fighting: d8 [8+3], Wild d6 [2], off hand -2 = 9 vs 5, success with a raise`
+    pub fn breakdown(&self) -> String {
+        let mut result = format!("{}: {}", self.name, self.trait_die.description());
+        if let Some(wild_die) = &self.wild_die {
+            write!(result, ", Wild {}", wild_die.description()).ok();
+        }
+        for (name, value) in &self.modifiers {
+            write!(result, ", {name} {value:+}").ok();
+        }
+        write!(result, " = {} vs {}, ", self.total(), self.target).ok();
+        result += match (self.is_success(), self.raises()) {
+            (false, _) => "failure".to_string(),
+            (true, 0) => "success".to_string(),
+            (true, 1) => "success with a raise".to_string(),
+            (true, raises) => format!("success with {raises} raises"),
+        }
+        .as_str();
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::mock::StepRng;
+
+    use super::{Dice, DieRoll, Modifiers, TraitRoll};
+
+    #[test]
+    fn test_die_roll() {
+        // always rolls 1 on d4
+        let roll = DieRoll::roll(Dice::D4, &mut StepRng::new(4, 0));
+        assert_eq!(vec![1], roll.rolls);
+        // 4 explodes, then 2
+        let roll = DieRoll::roll(Dice::D4, &mut StepRng::new(3, 2));
+        assert_eq!(vec![4, 2], roll.rolls);
+        assert_eq!(6, roll.total());
+    }
+
+    #[test]
+    fn test_trait_roll() {
+        // d4 rolls 1 and d6 rolls 5
+        let mut rng = StepRng::new(4, 0);
+        let roll = TraitRoll::roll("Fighting", Dice::D4, Modifiers::default(), &mut rng);
+        assert!(roll.wild_die.is_none());
+        assert_eq!(1, roll.total());
+        assert!(!roll.is_success());
+        assert_eq!(0, roll.successes());
+        assert_eq!("Fighting: d4 [1] = 1 vs 4, failure", roll.breakdown());
+
+        let modifiers = Modifiers::default()
+            .wild_card(true)
+            .with("off hand", -2)
+            .with("nothing", 0)
+            .against(2);
+        let roll = TraitRoll::roll("Fighting", Dice::D4, modifiers, &mut rng);
+        assert_eq!(5, roll.kept_die().total());
+        assert_eq!(3, roll.total());
+        assert!(roll.is_success());
+        assert_eq!(0, roll.raises());
+        assert_eq!(
+            "Fighting: d4 [1], Wild d6 [5], off hand -2 = 3 vs 2, success",
+            roll.breakdown()
+        );
+
+        let roll = TraitRoll::roll(
+            "Notice",
+            Dice::D6,
+            Modifiers::default().with("light", 7),
+            &mut rng,
+        );
+        assert_eq!(12, roll.total());
+        assert_eq!(2, roll.raises());
+        assert_eq!(3, roll.successes());
+        assert!(roll.breakdown().ends_with("success with 2 raises"));
+    }
+}
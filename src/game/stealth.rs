@@ -0,0 +1,123 @@
+//! Units in sight are noticed only after an opposed Notice vs Stealth roll
+
+use rand::Rng;
+
+use super::{
+    map::MIN_VISIBLE_LIGHT,
+    savage::{Modifiers, Skill},
+    ActionType, Avatar,
+};
+
+/// Unnoticed units in sight are checked again after this period
+pub const NOTICE_CHECK_TICKS: u128 = 10;
+/// Units at arm's length are noticed without rolls
+const AUTO_NOTICE_SQUARE_DISTANCE: u32 = 2;
+/// Notice penalty grows by one every this number of tiles
+const TILES_PER_DISTANCE_PENALTY: f32 = 10.0;
+/// Shadows help to hide
+const DIM_LIGHT: f32 = 0.5;
+const DIM_LIGHT_PENALTY: i8 = -2;
+const DARKNESS_PENALTY: i8 = -4;
+const MOVEMENT_BONUS: i8 = 2;
+
+/// Modifier to the observer's Notice roll
+pub fn notice_modifier(square_distance: u32, light: f32, moving: bool) -> i8 {
+    let mut modifier =
+        -((square_distance as f32).sqrt() / TILES_PER_DISTANCE_PENALTY).floor() as i8;
+    if light < MIN_VISIBLE_LIGHT {
+        modifier += DARKNESS_PENALTY;
+    } else if light < DIM_LIGHT {
+        modifier += DIM_LIGHT_PENALTY;
+    }
+    if moving {
+        modifier += MOVEMENT_BONUS;
+    }
+
+    modifier
+}
+
+/// Moving units are easier to notice
+pub fn is_moving(unit: &Avatar) -> bool {
+    unit.action.as_ref().is_some_and(|action| {
+        matches!(
+            action.typ,
+            ActionType::Walk(..) | ActionType::Swim(..) | ActionType::Climb(..)
+        )
+    })
+}
+
+/// `light` is the light level of the tile where the target is
+pub fn notices<R: Rng + ?Sized>(
+    observer: &Avatar,
+    target: &Avatar,
+    light: f32,
+    rng: &mut R,
+) -> bool {
+    let square_distance = observer.pos.square_distance(target.pos);
+    if square_distance <= AUTO_NOTICE_SQUARE_DISTANCE {
+        return true;
+    }
+    let modifier = notice_modifier(square_distance, light, is_moving(target));
+    let stealth = target.roll_skill(Skill::Stealth, Modifiers::default(), rng);
+    let modifiers = Modifiers::default()
+        .with("distance, light and movement", modifier)
+        .against(stealth.total());
+
+    observer
+        .roll_skill(Skill::Notice, modifiers, rng)
+        .is_success()
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::Point;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{
+        super::{
+            races::{
+                tests::personality::{old_queer, tester_girl},
+                Race,
+            },
+            Avatar, CharSheet, SkillLevel,
+        },
+        notice_modifier, notices,
+    };
+
+    #[test]
+    fn test_notice_modifier() {
+        assert_eq!(0, notice_modifier(2, 1.0, false));
+        assert_eq!(-2, notice_modifier(20 * 20, 1.0, false));
+        assert_eq!(-4, notice_modifier(20 * 20, 0.3, false));
+        assert_eq!(-2, notice_modifier(20 * 20, 0.3, true));
+        assert_eq!(-4, notice_modifier(25, 0.0, false));
+    }
+
+    #[test]
+    fn test_noticing() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut observer = Avatar::new(
+            tester_girl(),
+            CharSheet::default(Race::Gazan),
+            Point::new(0, 0),
+        );
+        observer.char_sheet.skills.notice = SkillLevel::D8;
+        let mut target = Avatar::new(old_queer(), CharSheet::default(Race::Bug), Point::new(1, 1));
+        target.char_sheet.skills.stealth = SkillLevel::D12;
+        assert!(notices(&observer, &target, 0.0, &mut rng));
+
+        let mut count = |target: &Avatar, light: f32| {
+            (0..100)
+                .filter(|_| notices(&observer, target, light, &mut rng))
+                .count()
+        };
+        target.pos = Point::new(5, 0);
+        let near = count(&target, 1.0);
+        target.pos = Point::new(40, 0);
+        let far = count(&target, 1.0);
+        let far_in_shadows = count(&target, 0.3);
+        assert!(near > far, "{near} <= {far}");
+        assert!(far > far_in_shadows, "{far} <= {far_in_shadows}");
+        assert!(far_in_shadows < 50);
+    }
+}
@@ -0,0 +1,176 @@
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+
+use super::calendar::{DateTime, Season, TICKS_PER_HOUR};
+
+/// Weather changes after this period at least
+const MIN_DURATION: u128 = TICKS_PER_HOUR;
+/// Weather changes after this period at most
+const MAX_DURATION: u128 = TICKS_PER_HOUR * 6;
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Weather {
+    #[serde(rename = "c")]
+    Clear,
+    #[serde(rename = "o")]
+    Overcast,
+    #[serde(rename = "r")]
+    Rain,
+    #[serde(rename = "f")]
+    Fog,
+    #[serde(rename = "s")]
+    Snow,
+}
+
+impl Weather {
+    /// Possible next states with their weights
+    fn transitions(self) -> &'static [(Weather, u8)] {
+        match self {
+            Weather::Clear => &[
+                (Weather::Clear, 4),
+                (Weather::Overcast, 4),
+                (Weather::Fog, 2),
+            ],
+            Weather::Overcast => &[
+                (Weather::Clear, 3),
+                (Weather::Overcast, 2),
+                (Weather::Rain, 3),
+                (Weather::Fog, 1),
+                (Weather::Snow, 1),
+            ],
+            Weather::Rain => &[
+                (Weather::Overcast, 4),
+                (Weather::Rain, 3),
+                (Weather::Fog, 1),
+            ],
+            Weather::Fog => &[
+                (Weather::Clear, 3),
+                (Weather::Overcast, 3),
+                (Weather::Fog, 1),
+            ],
+            Weather::Snow => &[(Weather::Overcast, 4), (Weather::Snow, 3)],
+        }
+    }
+
+    /// It snows instead of raining in winter and never snows in summer
+    fn in_season(self, season: Season) -> Weather {
+        match (self, season) {
+            (Weather::Rain, Season::Winter) => Weather::Snow,
+            (Weather::Snow, Season::Summer) => Weather::Rain,
+            _ => self,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Weather::Clear => "clear",
+            Weather::Overcast => "overcast",
+            Weather::Rain => "rain",
+            Weather::Fog => "fog",
+            Weather::Snow => "snow",
+        }
+    }
+
+    pub fn announcement(self) -> &'static str {
+        match self {
+            Weather::Clear => "The sky clears up",
+            Weather::Overcast => "Clouds cover the sky",
+            Weather::Rain => "It starts to rain",
+            Weather::Fog => "Fog rolls in",
+            Weather::Snow => "It starts to snow",
+        }
+    }
+
+    /// Fog and rain hide distant tiles
+    pub fn vision_range(self, range: i32) -> i32 {
+        match self {
+            Weather::Fog => range / 4,
+            Weather::Rain => range / 2,
+            _ => range,
+        }
+    }
+}
+
+/// Weather is a state machine, every state lasts for a random period
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WeatherState {
+    pub current: Weather,
+    /// Tick when the weather changes
+    #[serde(rename = "n")]
+    next_change: u128,
+}
+
+impl WeatherState {
+    pub fn new(current: Weather, next_change: u128) -> Self {
+        Self {
+            current,
+            next_change,
+        }
+    }
+
+    /// Returns new weather if it has changed
+    pub fn advance<R: Rng + ?Sized>(&mut self, current_tick: u128, rng: &mut R) -> Option<Weather> {
+        if current_tick < self.next_change {
+            return None;
+        }
+        let previous = self.current;
+        self.current = self
+            .current
+            .transitions()
+            .choose_weighted(rng, |(_, weight)| *weight)
+            .map_or(Weather::Clear, |(weather, _)| *weather)
+            .in_season(DateTime::from_tick(current_tick).season());
+        self.next_change = current_tick + rng.gen_range(MIN_DURATION..=MAX_DURATION);
+
+        (self.current != previous).then_some(self.current)
+    }
+}
+
+impl Default for WeatherState {
+    fn default() -> Self {
+        Self::new(Weather::Clear, MIN_DURATION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{
+        super::calendar::{DAYS_PER_MONTH, TICKS_PER_DAY},
+        Weather, WeatherState, MAX_DURATION,
+    };
+
+    #[test]
+    fn test_weather_changes() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut state = WeatherState::new(Weather::Rain, 100);
+        assert_eq!(None, state.advance(99, &mut rng));
+
+        let mut seen = vec![Weather::Rain];
+        let mut tick = 100;
+        for _ in 0..200 {
+            if let Some(weather) = state.advance(tick, &mut rng) {
+                seen.push(weather);
+            }
+            assert!(state.next_change > tick);
+            assert!(state.next_change <= tick + MAX_DURATION);
+            tick = state.next_change;
+        }
+        for weather in [
+            Weather::Clear,
+            Weather::Overcast,
+            Weather::Fog,
+            Weather::Snow,
+        ] {
+            assert!(seen.contains(&weather), "{weather:?} never happened");
+        }
+
+        let winter = TICKS_PER_DAY * DAYS_PER_MONTH * 9;
+        let mut state = WeatherState::new(Weather::Rain, winter);
+        for tick in (winter..).step_by(MAX_DURATION as usize).take(100) {
+            state.advance(tick, &mut rng);
+            assert_ne!(Weather::Rain, state.current);
+        }
+    }
+}
@@ -1,20 +1,50 @@
-use std::cell::{RefCell, RefMut};
-use std::collections::{HashMap, HashSet};
+use std::cell::{Cell, RefCell, RefMut};
+use std::collections::{hash_map::DefaultHasher, HashMap, HashSet};
 use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
 
 use geometry::{Direction, Point, TwoDimDirection};
+use rand::{distributions::Standard, Rng, SeedableRng};
+use rand_pcg::Pcg64;
 
 use crate::savefile::{self, GameView, Meta, SaveError};
 
 use super::{
-    map::{field_of_view_set, Fov, TerrainView},
+    actions::{implements::hit, ActionImpl},
+    ai::{
+        implements::{Flee, Follow, Wander},
+        BrainImpl,
+    },
+    bennies::{soaked_damage, BennyPrompt, BENNIES_PER_SESSION},
+    calendar::TICKS_PER_DAY,
+    experience::Reward,
+    log::{LogCategory, LogEvent},
+    map::{
+        ambient_light, field_of_view_set, items::helpers::corpse, line_of_sight, ChunkMemory, Fov,
+        Lighting, TerrainInteract, TerrainView, MAX_LIGHT_RADIUS,
+    },
     races::{Appearance, FurColor, Gender, MainHand, Mind, Personality, Race, Sex},
-    savage::CharSheet,
-    Action, Avatar, Chunk, ChunkPos, Log, Map, TilePos,
+    savage::{Attribute, CharSheet, Modifiers, Skill, TraitRoll},
+    stealth::{notices, NOTICE_CHECK_TICKS},
+    Action, Avatar, Chunk, ChunkPos, DateTime, Item, Log, Map, TilePos, Weather, WeatherState,
 };
 
-// TODO: weather and outside lighting system
 const VISION_RANGE: i32 = 64;
+/// Extra vision range for standing on a boulder or a tree
+const HEIGHT_VISION_BONUS: i32 = 32;
+/// Units out of deep water recover one level of Fatigue once in this period
+const FATIGUE_RECOVERY_TICKS: u128 = 3000;
+/// Rain and snow change terrain around the player once in this period
+const WEATHER_EFFECT_TICKS: u128 = 600;
+
+/// Serializable RNG owned by the world, every random thing in game should use it
+pub type WorldRng = Pcg64;
+
+pub fn rng_from_seed(seed: &str) -> WorldRng {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    WorldRng::seed_from_u64(hasher.finish())
+}
 
 pub struct World {
     pub meta: Meta,
@@ -24,18 +54,30 @@ pub struct World {
     loaded_units: HashSet<usize>,
     map: RefCell<Map>,
     fov: Fov,
+    lighting: Lighting,
     log: RefCell<Log>,
-    // TODO: add Rng created with seed
+    rng: RefCell<WorldRng>,
+    weather: WeatherState,
+    /// World waits until the player decides whether to spend a Benny
+    benny_prompt: Option<BennyPrompt>,
+    /// Failed roll the player decided to keep, it is used instead of a new roll
+    kept_roll: Cell<Option<TraitRoll>>,
+    /// No new prompts while the current one is being resolved
+    resolving_prompt: bool,
     // TODO: add WorldLog
 }
 
 impl World {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         meta: Meta,
         game_view: GameView,
         log: Log,
+        rng: WorldRng,
+        weather: WeatherState,
         units: Vec<Avatar>,
         chunks: HashMap<ChunkPos, Chunk>,
+        memory: HashMap<ChunkPos, ChunkMemory>,
     ) -> Self {
         let changed = chunks.keys().copied().collect();
         let loaded_units = HashSet::from([0]);
@@ -44,13 +86,20 @@ impl World {
                 seed: meta.seed.clone(),
                 chunks,
                 changed,
+                memory,
             }),
             meta,
             game_view,
             units,
             loaded_units,
             fov: Fov::default(),
+            lighting: Lighting::default(),
             log: RefCell::new(log),
+            rng: RefCell::new(rng),
+            weather,
+            benny_prompt: None,
+            kept_roll: Cell::new(None),
+            resolving_prompt: false,
         };
         world.load_units();
         world.calc_fov();
@@ -58,126 +107,230 @@ impl World {
     }
 
     pub fn create(meta: Meta, avatar: Avatar) -> Self {
+        let rng = rng_from_seed(&meta.seed);
         Self::new(
             meta,
             GameView::default(),
             Log::new(),
+            rng,
+            WeatherState::default(),
             vec![avatar],
             HashMap::new(),
+            HashMap::new(),
         )
     }
 
     /// Calls one time after world is created
     pub fn init(mut self) -> Self {
+        let start = self.map().find_start_pos(self.player().pos);
+        self.player_mut().pos = start;
+        self.refresh_bennies();
         // TODO: don't forget to remove
-        self.add_unit(Avatar::new(
-            Personality::new(
-                false,
-                Appearance {
-                    race: Race::Gazan,
-                    age: 25,
-                    fur_color: Some(FurColor::LightBrown),
-                    sex: Sex::Male,
-                },
-                Mind {
-                    name: "Dragan".to_string(),
-                    gender: Gender::Male,
-                    main_hand: MainHand::Right,
-                    alive: true,
-                },
-            ),
-            CharSheet::default(Race::Gazan),
-            Point::new(0, -5),
-        ));
-        self.add_unit(Avatar::new(
-            Personality::new(
-                false,
-                Appearance {
-                    race: Race::Nyarnik,
-                    age: 22,
-                    fur_color: None,
-                    sex: Sex::Female,
-                },
-                Mind {
-                    name: "Shasha".to_string(),
-                    gender: Gender::Female,
-                    main_hand: MainHand::Left,
-                    alive: true,
-                },
-            ),
-            CharSheet::default(Race::Nyarnik),
-            Point::new(-3, -5),
-        ));
-        self.add_unit(Avatar::new(
-            Personality::new(
-                false,
-                Appearance {
-                    race: Race::Gazan,
-                    age: 20,
-                    fur_color: Some(FurColor::Ginger),
-                    sex: Sex::Male,
-                },
-                Mind {
-                    name: "Yasma".to_string(),
-                    gender: Gender::Male,
-                    main_hand: MainHand::Right,
-                    alive: true,
-                },
-            ),
-            CharSheet::default(Race::Gazan),
-            Point::new(3, -5),
-        ));
-        self.add_unit(Avatar::new(
-            Personality::new(
-                false,
-                Appearance {
-                    race: Race::Lagnam,
-                    age: 25,
-                    fur_color: Some(FurColor::White),
-                    sex: Sex::Male,
-                },
-                Mind {
-                    name: "Grem".to_string(),
-                    gender: Gender::Male,
-                    main_hand: MainHand::Right,
-                    alive: true,
-                },
-            ),
-            CharSheet::default(Race::Lagnam),
-            Point::new(6, -5),
-        ));
-        self.add_unit(Avatar::new(
-            Personality::new(
-                false,
-                Appearance {
-                    race: Race::Totik,
-                    age: 20,
-                    fur_color: None,
-                    sex: Sex::Male,
-                },
-                Mind {
-                    name: "Unnamed totik".to_string(),
-                    gender: Gender::Male,
-                    main_hand: MainHand::Right,
-                    alive: true,
-                },
-            ),
-            CharSheet::default(Race::Totik),
-            Point::new(-6, -5),
-        ));
+        self.add_unit(
+            Avatar::new(
+                Personality::new(
+                    false,
+                    Appearance {
+                        race: Race::Gazan,
+                        age: 25,
+                        fur_color: Some(FurColor::LightBrown),
+                        sex: Sex::Male,
+                    },
+                    Mind {
+                        name: "Dragan".to_string(),
+                        gender: Gender::Male,
+                        main_hand: MainHand::Right,
+                        alive: true,
+                    },
+                ),
+                CharSheet::default(Race::Gazan),
+                start + Point::new(0, -5),
+            )
+            .with_ai(Follow::new(0).into())
+            .with_wild_card(),
+        );
+        self.add_unit(
+            Avatar::new(
+                Personality::new(
+                    false,
+                    Appearance {
+                        race: Race::Nyarnik,
+                        age: 22,
+                        fur_color: None,
+                        sex: Sex::Female,
+                    },
+                    Mind {
+                        name: "Shasha".to_string(),
+                        gender: Gender::Female,
+                        main_hand: MainHand::Left,
+                        alive: true,
+                    },
+                ),
+                CharSheet::default(Race::Nyarnik),
+                start + Point::new(-3, -5),
+            )
+            .with_ai(Wander::default().into())
+            .with_wild_card(),
+        );
+        self.add_unit(
+            Avatar::new(
+                Personality::new(
+                    false,
+                    Appearance {
+                        race: Race::Gazan,
+                        age: 20,
+                        fur_color: Some(FurColor::Ginger),
+                        sex: Sex::Male,
+                    },
+                    Mind {
+                        name: "Yasma".to_string(),
+                        gender: Gender::Male,
+                        main_hand: MainHand::Right,
+                        alive: true,
+                    },
+                ),
+                CharSheet::default(Race::Gazan),
+                start + Point::new(3, -5),
+            )
+            .with_ai(Wander::default().into())
+            .with_wild_card(),
+        );
+        self.add_unit(
+            Avatar::new(
+                Personality::new(
+                    false,
+                    Appearance {
+                        race: Race::Lagnam,
+                        age: 25,
+                        fur_color: Some(FurColor::White),
+                        sex: Sex::Male,
+                    },
+                    Mind {
+                        name: "Grem".to_string(),
+                        gender: Gender::Male,
+                        main_hand: MainHand::Right,
+                        alive: true,
+                    },
+                ),
+                CharSheet::default(Race::Lagnam),
+                start + Point::new(6, -5),
+            )
+            .with_ai(Flee::new(0).into())
+            .with_wild_card(),
+        );
+        self.add_unit(
+            Avatar::new(
+                Personality::new(
+                    false,
+                    Appearance {
+                        race: Race::Totik,
+                        age: 20,
+                        fur_color: None,
+                        sex: Sex::Male,
+                    },
+                    Mind {
+                        name: "Unnamed totik".to_string(),
+                        gender: Gender::Male,
+                        main_hand: MainHand::Right,
+                        alive: true,
+                    },
+                ),
+                CharSheet::default(Race::Totik),
+                start + Point::new(-6, -5),
+            )
+            .with_ai(Wander::default().into())
+            .with_wild_card(),
+        );
 
         self.units.iter().enumerate().for_each(|(i, unit)| {
-            self.map.borrow_mut().get_tile_mut(unit.pos).on_step(i);
+            self.map
+                .borrow_mut()
+                .get_tile_mut(unit.pos)
+                .on_step(i, &mut *self.rng.borrow_mut());
         });
+        self.calc_fov();
+        self.update_detection();
         self
     }
 
+    /// Visible tiles are in line of sight and lit enough
     pub fn calc_fov(&mut self) {
-        self.fov.set_visible(field_of_view_set(
-            self.player().pos,
-            VISION_RANGE,
-            &self.map.borrow(),
+        let pos = self.player().pos;
+        let range = if self.map().get_tile(pos).terrain.is_climbable() {
+            VISION_RANGE + HEIGHT_VISION_BONUS
+        } else {
+            VISION_RANGE
+        } + self.player().char_sheet.vision_bonus();
+        let range = self.weather.current.vision_range(range);
+        self.calc_lighting(pos, range);
+        let mut visible = field_of_view_set(pos, range, &self.map.borrow());
+        // one can see at arm's length even in the darkness
+        visible.retain(|&p| p.square_distance(pos) <= 2 || self.lighting.is_lit(p));
+        self.fov.set_visible(visible);
+        self.remember_visible();
+    }
+
+    /// Player remembers how the seen tiles look
+    fn remember_visible(&mut self) {
+        let mut map = self.map.borrow_mut();
+        let mut discovered = 0;
+        for &pos in self.fov.visible() {
+            if map.remember_tile(pos) {
+                discovered += 1;
+            }
+        }
+        drop(map);
+        // surroundings of the starting point are not a discovery
+        if self.meta.current_tick > 0 {
+            for _ in 0..discovered {
+                self.award_experience(Reward::Discovery);
+            }
+        }
+    }
+
+    /// Only the player gains experience, new Advances are spent on the character sheet
+    pub fn award_experience(&mut self, reward: Reward) {
+        let amount = reward.experience();
+        let advances = self.player_mut().char_sheet.add_experience(amount);
+        let pos = self.player().pos;
+        self.log().push(LogEvent::new(
+            format!("You gain {amount} experience for {}", reward.reason()),
+            pos,
+            LogCategory::Info,
         ));
+        if advances > 0 {
+            self.log().push(LogEvent::new(
+                "You have an Advance to spend, press [@] to open the character sheet",
+                pos,
+                LogCategory::Success,
+            ));
+        }
+    }
+
+    fn calc_lighting(&mut self, center: Point, range: i32) {
+        let mut lighting = Lighting::new(ambient_light(self.meta.current_tick));
+        // there is no need for emitters at a day
+        if lighting.level(center) < 1.0 {
+            let map = self.map.borrow();
+            // light from behind the vision range can reach visible tiles
+            let range = range + i32::from(MAX_LIGHT_RADIUS);
+            let mut emitters =
+                map.light_emitters(center + (-range, -range), center + (range, range));
+            emitters.extend(self.loaded_units.iter().filter_map(|&i| {
+                let unit = self.get_unit(i);
+                let light = unit.hands.items().map(Item::light).max().unwrap_or(0);
+                (light > 0).then_some((unit.pos, light))
+            }));
+            for (pos, radius) in emitters {
+                lighting.add_emitter(pos, radius, &map);
+            }
+        }
+        self.lighting = lighting;
+    }
+
+    /// From 0.0 for pitch black to 1.0 for daylight
+    pub fn light_level(&self, pos: Point) -> f32 {
+        self.lighting.level(pos)
     }
 
     // TODO: move this to savefile::save
@@ -195,6 +348,18 @@ impl World {
                 .map_err(SaveError::from)?
                 .as_str(),
         );
+        data.push('\n');
+        data.push_str(
+            serde_json::to_string(&self.rng)
+                .map_err(SaveError::from)?
+                .as_str(),
+        );
+        data.push('\n');
+        data.push_str(
+            serde_json::to_string(&self.weather)
+                .map_err(SaveError::from)?
+                .as_str(),
+        );
         for unit in &self.units {
             data.push('\n');
             data.push_str(
@@ -215,6 +380,14 @@ impl World {
             );
         }
         data.push_str("\n/chunks");
+        // memory is saved apart from chunks to not save every seen chunk whole
+        let memory: Vec<_> = map.memory.iter().filter(|(_, m)| !m.is_empty()).collect();
+        data.push('\n');
+        data.push_str(
+            serde_json::to_string(&memory)
+                .map_err(SaveError::from)?
+                .as_str(),
+        );
 
         Ok(data)
     }
@@ -235,10 +408,41 @@ impl World {
         self.map.borrow_mut()
     }
 
+    pub fn rng(&self) -> RefMut<WorldRng> {
+        self.rng.borrow_mut()
+    }
+
+    pub fn now(&self) -> DateTime {
+        DateTime::from_tick(self.meta.current_tick)
+    }
+
+    pub fn weather(&self) -> Weather {
+        self.weather.current
+    }
+
     pub fn is_visible<P: Into<Point>>(&self, pos: P) -> bool {
         self.fov.visible().contains(&pos.into())
     }
 
+    /// Player sees the unit and is aware of it
+    pub fn is_noticed(&self, unit_id: usize) -> bool {
+        unit_id == 0
+            || (self.player().noticed.contains(&unit_id)
+                && self.is_visible(self.get_unit(unit_id).pos))
+    }
+
+    /// Alive units player can see right now
+    pub fn visible_units(&self) -> HashSet<usize> {
+        self.loaded_units
+            .iter()
+            .copied()
+            .filter(|&i| {
+                let unit = self.get_unit(i);
+                !unit.is_player() && unit.is_alive() && self.is_noticed(i)
+            })
+            .collect()
+    }
+
     pub fn get_unit(&self, unit_id: usize) -> &Avatar {
         self.units.get(unit_id).unwrap()
     }
@@ -266,7 +470,9 @@ impl World {
                 unit.vision = dir;
             }
         }
-        self.map().get_tile_mut(pos).on_step(unit_id);
+        self.map()
+            .get_tile_mut(pos)
+            .on_step(unit_id, &mut *self.rng());
         if unit_id == 0 && old_chunk != pos.to_chunk().0 {
             self.load_units();
         }
@@ -281,6 +487,9 @@ impl World {
 
     // TODO: move this somewhere else
     pub fn this_is(&self, pos: Point, multiline: bool) -> String {
+        if !self.is_visible(pos) {
+            return self.remembered_is(pos, multiline);
+        }
         let mut map = self.map();
         let tile = map.get_tile(pos);
         let mut this_is = format!("This is a {}.", tile.terrain.name());
@@ -288,7 +497,13 @@ impl World {
             this_is = this_is.replace(". ", ".\n");
         }
 
-        if !tile.items.is_empty() || !tile.units.is_empty() {
+        let units: Vec<usize> = tile
+            .units
+            .iter()
+            .copied()
+            .filter(|&i| self.is_noticed(i))
+            .collect();
+        if !tile.items.is_empty() || !units.is_empty() {
             this_is.push(if multiline { '\n' } else { ' ' });
             this_is.push_str("Here you see: ");
             if multiline {
@@ -296,7 +511,7 @@ impl World {
             }
         }
 
-        let mut items: Vec<String> = Vec::with_capacity(tile.items.len() + tile.units.len());
+        let mut items: Vec<String> = Vec::with_capacity(tile.items.len() + units.len());
         if !tile.items.is_empty() {
             items.append(
                 &mut tile
@@ -306,10 +521,9 @@ impl World {
                     .collect(),
             );
         }
-        if !tile.units.is_empty() {
+        if !units.is_empty() {
             items.append(
-                &mut tile
-                    .units
+                &mut units
                     .iter()
                     .copied()
                     .map(|i| {
@@ -325,6 +539,26 @@ impl World {
         this_is
     }
 
+    fn remembered_is(&self, pos: Point, multiline: bool) -> String {
+        let map = self.map();
+        let Some(memory) = map.remembered(pos) else {
+            return "You don't know what is there.".to_string();
+        };
+        let mut this_is = if let Some(item) = &memory.item {
+            format!(
+                "You remember a {} here. There was a {}.",
+                memory.name, item.name
+            )
+        } else {
+            format!("You remember a {} here.", memory.name)
+        };
+        if multiline {
+            this_is = this_is.replace(". ", ".\n");
+        }
+
+        this_is
+    }
+
     /// Doing actions that should be done
     fn act(&mut self) {
         let actions: Vec<Action> = self
@@ -353,6 +587,163 @@ impl World {
         new_id
     }
 
+    /// Unit stays in units list but leaves its corpse and items on the tile
+    pub fn kill_unit(&mut self, unit_id: usize) {
+        let unit = self.get_unit_mut(unit_id);
+        unit.personality.mind.alive = false;
+        unit.action = None;
+        let pos = unit.pos;
+        let mut items: Vec<Item> = unit
+            .hands
+            .take_all()
+            .into_iter()
+            .chain(unit.wear.take_all())
+            .collect();
+        items.push(corpse(&unit.personality.mind.name));
+        let name = unit.name_for_actions();
+
+        let mut map = self.map();
+        let tile = map.get_tile_mut(pos);
+        tile.off_step(unit_id);
+        if !tile.sinks_items() {
+            tile.items.append(&mut items);
+        }
+        drop(map);
+
+        self.log().push(LogEvent::new(
+            format!("{name} died"),
+            pos,
+            LogCategory::Danger,
+        ));
+    }
+
+    pub fn benny_prompt(&self) -> Option<&BennyPrompt> {
+        self.benny_prompt.as_ref()
+    }
+
+    /// Trait roll of unit's action, the player's kept roll is used once instead of a new one
+    pub fn roll_skill(&self, unit_id: usize, skill: Skill, modifiers: Modifiers) -> TraitRoll {
+        if unit_id == 0 {
+            if let Some(roll) = self.kept_roll.take() {
+                return roll;
+            }
+        }
+        self.get_unit(unit_id)
+            .roll_skill(skill, modifiers, &mut *self.rng())
+    }
+
+    /// Returns `true` if the action should wait for the player's decision
+    pub fn offer_reroll(&mut self, action: &Action, roll: &TraitRoll) -> bool {
+        if action.owner != 0
+            || self.resolving_prompt
+            || self.benny_prompt.is_some()
+            || self.player().bennies == 0
+        {
+            return false;
+        }
+        self.benny_prompt = Some(BennyPrompt::Reroll {
+            action: action.clone(),
+            roll: roll.clone(),
+        });
+        true
+    }
+
+    /// Returns `true` if the damage should wait for the player's decision
+    pub fn offer_soak(
+        &mut self,
+        target: usize,
+        attacker_name: &str,
+        damage: u8,
+        details: &[String],
+    ) -> bool {
+        // one prompt at a time, everything else in the same tick applies without asking
+        if target != 0
+            || self.resolving_prompt
+            || self.benny_prompt.is_some()
+            || self.player().bennies == 0
+        {
+            return false;
+        }
+        let player = self.player();
+        if player.char_sheet.wounds_from(damage, player.armor()) == 0 {
+            return false;
+        }
+        self.benny_prompt = Some(BennyPrompt::Soak {
+            attacker_name: attacker_name.to_string(),
+            damage,
+            details: details.to_vec(),
+        });
+        true
+    }
+
+    pub fn answer_benny_prompt(&mut self, spend: bool) {
+        let Some(prompt) = self.benny_prompt.take() else {
+            return;
+        };
+        let pos = self.player().pos;
+        if spend {
+            self.player_mut().bennies -= 1;
+            self.log().push(LogEvent::new(
+                format!("You spend a Benny, {} left", self.player().bennies),
+                pos,
+                LogCategory::Info,
+            ));
+        }
+        self.resolving_prompt = true;
+        match prompt {
+            BennyPrompt::Reroll { action, roll } => {
+                if spend {
+                    // there could be another failure and another Benny
+                    self.resolving_prompt = false;
+                } else {
+                    self.kept_roll.set(Some(roll));
+                }
+                action.typ.on_finish(&action, self);
+                self.kept_roll.set(None);
+            }
+            BennyPrompt::Soak {
+                attacker_name,
+                damage,
+                mut details,
+            } => {
+                let damage = if spend {
+                    let player = self.player();
+                    let wounds = player.char_sheet.wounds_from(damage, player.armor());
+                    let vigor = player.roll_attribute(
+                        Attribute::Vigor,
+                        Modifiers::default(),
+                        &mut *self.rng(),
+                    );
+                    let soaked = vigor.successes();
+                    if soaked >= wounds {
+                        self.log().push(
+                            LogEvent::new("You soak all the damage", pos, LogCategory::Success)
+                                .with_roll(&vigor),
+                        );
+                        0
+                    } else {
+                        details.push(vigor.breakdown());
+                        soaked_damage(damage, soaked)
+                    }
+                } else {
+                    damage
+                };
+                if damage > 0 {
+                    hit(self, &attacker_name, 0, damage, details);
+                }
+            }
+        }
+        self.resolving_prompt = false;
+    }
+
+    /// New session begins every morning
+    fn refresh_bennies(&mut self) {
+        let player = self.player_mut();
+        if player.wild_card && player.bennies < BENNIES_PER_SESSION {
+            player.bennies = BENNIES_PER_SESSION;
+        }
+    }
+
     fn load_units(&mut self) {
         self.loaded_units.clear();
         let center = self.player().pos;
@@ -367,69 +758,238 @@ impl World {
         }
     }
 
+    /// Gives new actions to loaded npcs that have nothing to do
+    fn plan_npcs(&mut self) {
+        let mut unit_ids: Vec<usize> = self
+            .loaded_units
+            .iter()
+            .copied()
+            .filter(|&i| {
+                let unit = self.get_unit(i);
+                !unit.is_player() && unit.is_alive() && unit.action.is_none()
+            })
+            .collect();
+        // HashSet order is random, so sort to keep rng calls reproducible
+        unit_ids.sort_unstable();
+        for unit_id in unit_ids {
+            if let Some(mut brain) = self.get_unit_mut(unit_id).ai.take() {
+                brain.plan(unit_id, self);
+                let action = brain
+                    .action(unit_id, self)
+                    .and_then(|typ| Action::new(unit_id, typ, self).ok());
+                let unit = self.get_unit_mut(unit_id);
+                unit.ai = Some(brain);
+                unit.action = action;
+            }
+        }
+    }
+
+    /// Line of sight for NPCs, player uses the field of view
+    fn can_see(&self, observer: usize, target: usize) -> bool {
+        let target_pos = self.get_unit(target).pos;
+        if observer == 0 {
+            return self.is_visible(target_pos);
+        }
+        let observer = self.get_unit(observer);
+        let pos = observer.pos;
+        let range = self
+            .weather
+            .current
+            .vision_range(VISION_RANGE + observer.char_sheet.vision_bonus());
+        let square_distance = pos.square_distance(target_pos);
+        square_distance <= (range * range) as u32
+            && (square_distance <= 2 || self.lighting.is_lit(target_pos))
+            && line_of_sight(pos, target_pos, &self.map.borrow())
+    }
+
+    /// Units notice each other after a roll and forget about ones out of sight
+    fn update_detection(&mut self) {
+        let mut unit_ids: Vec<usize> = self
+            .loaded_units
+            .iter()
+            .copied()
+            .filter(|&i| self.get_unit(i).is_alive())
+            .collect();
+        // HashSet order is random, so sort to keep rng calls reproducible
+        unit_ids.sort_unstable();
+        for &observer in &unit_ids {
+            let mut noticed = std::mem::take(&mut self.get_unit_mut(observer).noticed);
+            noticed.retain(|&i| unit_ids.contains(&i) && self.can_see(observer, i));
+            for &target in &unit_ids {
+                if target == observer
+                    || noticed.contains(&target)
+                    || !self.can_see(observer, target)
+                {
+                    continue;
+                }
+                let light = self.light_level(self.get_unit(target).pos);
+                if notices(
+                    self.get_unit(observer),
+                    self.get_unit(target),
+                    light,
+                    &mut *self.rng(),
+                ) {
+                    noticed.insert(target);
+                }
+            }
+            self.get_unit_mut(observer).noticed = noticed;
+        }
+    }
+
     pub const BUBBLE_SQUARE_RADIUS: u32 = 128 * 128;
     pub const SPEND_LIMIT: u32 = 100; // TODO: probably it should be about 10-50
 
+    /// Adds NPCs from the structures of recently generated chunks
+    fn spawn_npcs(&mut self) {
+        let spawns = self.map().take_spawns();
+        for (pos, spawn) in spawns {
+            let avatar = {
+                let mut rng = self.rng();
+                let race = spawn.race.unwrap_or_else(|| rng.sample(Standard));
+                Avatar::new(
+                    Personality::random_of_race(&mut *rng, race, false, true),
+                    CharSheet::random(&mut *rng, race),
+                    pos,
+                )
+                .with_ai(Wander::default().into())
+            };
+            self.add_unit(avatar);
+        }
+    }
+
+    fn recover_fatigue(&mut self) {
+        for &unit_id in &self.loaded_units {
+            let unit = &self.units[unit_id];
+            if unit.char_sheet.fatigue == 0
+                || self
+                    .map
+                    .borrow_mut()
+                    .get_tile(unit.pos)
+                    .terrain
+                    .is_deep_water()
+            {
+                continue;
+            }
+            self.units[unit_id].char_sheet.fatigue -= 1;
+        }
+    }
+
+    fn update_weather(&mut self) {
+        let changed = self
+            .weather
+            .advance(self.meta.current_tick, &mut *self.rng.borrow_mut());
+        if let Some(weather) = changed {
+            self.log().push(LogEvent::new(
+                weather.announcement(),
+                self.player().pos,
+                LogCategory::Info,
+            ));
+        }
+        if self.meta.current_tick % WEATHER_EFFECT_TICKS == 0 {
+            self.apply_weather();
+        }
+    }
+
+    /// Only chunks around the player are changed, it is where weather is seen
+    fn apply_weather(&mut self) {
+        let weather = self.weather.current;
+        if matches!(weather, Weather::Overcast | Weather::Fog) {
+            return;
+        }
+        let (center, _) = self.player().pos.to_chunk();
+        let mut map = self.map.borrow_mut();
+        let mut rng = self.rng.borrow_mut();
+        for x in center.x - 1..=center.x + 1 {
+            for y in center.y - 1..=center.y + 1 {
+                for tile in &mut map.get_chunk_mut(ChunkPos::new(x, y)).tiles {
+                    tile.apply_weather(weather, &mut *rng);
+                }
+            }
+        }
+    }
+
     pub fn tick(&mut self) {
+        self.spawn_npcs();
         self.act();
 
         let mut spend = 0;
-        while self.player().action.is_some() && spend < Self::SPEND_LIMIT {
+        while self.player().action.is_some()
+            && self.benny_prompt.is_none()
+            && spend < Self::SPEND_LIMIT
+        {
             self.meta.current_tick += 1;
             spend += 1;
+            if self.meta.current_tick % FATIGUE_RECOVERY_TICKS == 0 {
+                self.recover_fatigue();
+            }
+            if self.meta.current_tick % TICKS_PER_DAY == 0 {
+                self.refresh_bennies();
+                if self.player().is_alive() {
+                    self.award_experience(Reward::Survival);
+                }
+            }
+            self.update_weather();
+            if self.meta.current_tick % NOTICE_CHECK_TICKS == 0 {
+                self.update_detection();
+            }
+            self.plan_npcs();
             self.act();
-
-            // TODO: npcs AI
-            // let mut unit_wants_actions = HashMap::new();
-            // for (unit_id, unit) in self.units.iter_mut().skip(1).enumerate() {
-            //     if unit.action.is_none() {
-            //         if let Some(brain) = &mut unit.ai {
-            //             brain.plan();
-            //             if let Some(action_type) = brain.action() {
-            //                 // +1 is because we skipped first one in enumeration
-            //                 unit_wants_actions.insert(unit_id + 1, action_type);
-            //             }
-            //         }
-            //     }
-            // }
-            // for (unit_id, typ) in unit_wants_actions {
-            //     self.units.get_mut(unit_id).unwrap().action = Action::new(unit_id, typ, self).ok();
-            // }
         }
+        // time of day and carried torches change what is visible
+        self.calc_fov();
     }
 }
 
 #[cfg(test)]
 pub mod tests {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
 
     use geometry::Point;
 
     use super::{
         super::{
-            actions::implements::{Skip, Walk},
-            map::terrains::{Boulder, BoulderSize, Dirt},
+            actions::implements::{hit, Attack, Skip, Walk},
+            map::{
+                items::helpers::{axe, torch},
+                terrains::{Boulder, BoulderSize, Dirt, Grass, GrassVariant},
+                Passage, Terrain, TerrainInteract,
+            },
             races::{
                 tests::personality::{old_queer, tester_girl},
                 Race,
             },
+            Dice, Hand, SkillLevel, Weather, WeatherState,
         },
+        rng_from_seed,
         savefile::{GameView, Meta},
-        Action, Avatar, CharSheet, Direction, Log, TerrainView, World,
+        Action, Avatar, BennyPrompt, CharSheet, Direction, Log, Reward, TerrainView, World,
+        BENNIES_PER_SESSION, TICKS_PER_DAY,
     };
 
     pub fn prepare_world() -> World {
-        World::new(
+        let mut world = World::new(
             Meta::new("test", "test"),
             GameView::default(),
             Log::new(),
+            rng_from_seed("test"),
+            WeatherState::default(),
             vec![Avatar::dressed_default(
                 tester_girl(),
                 CharSheet::default(Race::Gazan),
                 Point::new(0, 0),
             )],
             HashMap::new(),
-        )
+            HashMap::new(),
+        );
+        // tests don't care about biomes around
+        for x in -10..=10 {
+            for y in -10..=10 {
+                world.map().get_tile_mut(Point::new(x, y)).terrain = Dirt::default().into();
+            }
+        }
+        world.calc_fov();
+
+        world
     }
 
     pub fn add_npc(world: &mut World, pos: Point) -> usize {
@@ -487,4 +1047,235 @@ pub mod tests {
         assert!(world.is_visible(Point::new(2, 0)));
         assert!(!world.is_visible(Point::new(3, 0)));
     }
+
+    #[test]
+    pub fn test_remembering_tiles() {
+        let mut world = prepare_world();
+        let pos = Point::new(3, 0);
+        world.map().get_tile_mut(pos).items.push(axe());
+        world.calc_fov();
+        let name = world.map().get_tile(pos).terrain.name().to_string();
+        assert!(world.this_is(pos, false).starts_with("This is a"));
+
+        world.map().get_tile_mut(pos).items.clear();
+        world.fov.set_visible(HashSet::new());
+        assert_eq!(
+            format!("You remember a {name} here. There was a axe."),
+            world.this_is(pos, false)
+        );
+        assert_eq!(
+            "You don't know what is there.",
+            world.this_is(Point::new(100, 0), false)
+        );
+
+        world.calc_fov();
+        assert!(world.map().remembered(pos).unwrap().item.is_none());
+    }
+
+    #[test]
+    pub fn test_noticing_units() {
+        let mut world = prepare_world();
+        let near = add_npc(&mut world, Point::new(1, 0));
+        let far = add_npc(&mut world, Point::new(5, 0));
+        assert!(!world.is_noticed(near));
+        world.update_detection();
+        assert!(world.is_noticed(near));
+        assert!(world.get_unit(near).noticed.contains(&0));
+        for _ in 0..50 {
+            world.update_detection();
+        }
+        assert!(world.is_noticed(far));
+        assert!(world.visible_units().contains(&far));
+
+        // midnight, units out of sight are forgotten
+        world.meta.current_tick = 16 * 36_000;
+        world.calc_fov();
+        world.update_detection();
+        assert!(world.is_noticed(near));
+        assert!(!world.is_noticed(far));
+        assert!(!world.player().noticed.contains(&far));
+        assert!(!world.get_unit(far).noticed.contains(&0));
+    }
+
+    #[test]
+    pub fn test_spending_bennies() {
+        let mut world = prepare_world();
+        let npc = add_npc(&mut world, Point::new(1, 0));
+        // parry 8 is too much for untrained player
+        world.get_unit_mut(npc).char_sheet.skills.fighting = SkillLevel::D12;
+        world.player_mut().bennies = 2;
+        let attack_until_prompt = |world: &mut World| {
+            for _ in 0..20 {
+                world.player_mut().action = Some(
+                    Action::new(
+                        0,
+                        Attack {
+                            dir: Direction::East,
+                        }
+                        .into(),
+                        world,
+                    )
+                    .unwrap(),
+                );
+                while world.player().action.is_some() {
+                    world.tick();
+                }
+                if world.benny_prompt().is_some() {
+                    return;
+                }
+            }
+            panic!("player never failed an attack");
+        };
+
+        attack_until_prompt(&mut world);
+        assert!(matches!(
+            world.benny_prompt(),
+            Some(BennyPrompt::Reroll { .. })
+        ));
+        world.answer_benny_prompt(false);
+        assert!(world.benny_prompt().is_none());
+        assert_eq!(2, world.player().bennies);
+        assert!(world
+            .log()
+            .new_events()
+            .iter()
+            .any(|e| e.msg.contains("but miss")));
+
+        attack_until_prompt(&mut world);
+        world.answer_benny_prompt(true);
+        assert_eq!(1, world.player().bennies);
+        world.answer_benny_prompt(false);
+        assert!(world.benny_prompt().is_none());
+
+        let one_wound = world.player().toughness() + 4;
+        hit(&mut world, "Dragan", 0, one_wound, Vec::new());
+        assert!(matches!(
+            world.benny_prompt(),
+            Some(BennyPrompt::Soak { .. })
+        ));
+        assert_eq!(0, world.player().char_sheet.wounds);
+        world.answer_benny_prompt(false);
+        assert_eq!(1, world.player().char_sheet.wounds);
+
+        hit(&mut world, "Dragan", 0, one_wound, Vec::new());
+        world.answer_benny_prompt(true);
+        assert_eq!(0, world.player().bennies);
+        assert!(world.player().char_sheet.wounds <= 2);
+        let wounds = world.player().char_sheet.wounds;
+        // no Bennies, no questions
+        hit(&mut world, "Dragan", 0, one_wound, Vec::new());
+        assert!(world.benny_prompt().is_none());
+        assert_eq!(wounds + 1, world.player().char_sheet.wounds);
+    }
+
+    #[test]
+    pub fn test_two_hits_in_one_tick() {
+        let mut world = prepare_world();
+        world.player_mut().bennies = 2;
+        let one_wound = world.player().toughness() + 4;
+        // both attackers act in the same pass of act()
+        hit(&mut world, "Dragan", 0, one_wound, Vec::new());
+        hit(&mut world, "Old Queer", 0, one_wound, Vec::new());
+        assert!(matches!(
+            world.benny_prompt(),
+            Some(BennyPrompt::Soak { .. })
+        ));
+        assert_eq!(1, world.player().char_sheet.wounds);
+        world.answer_benny_prompt(false);
+        assert_eq!(2, world.player().char_sheet.wounds);
+    }
+
+    #[test]
+    pub fn test_refreshing_bennies() {
+        let mut world = prepare_world();
+        world.player_mut().wild_card = true;
+        world.meta.current_tick = TICKS_PER_DAY - 1;
+        world.player_mut().action = Some(Action::new(0, Skip {}.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        assert_eq!(BENNIES_PER_SESSION, world.player().bennies);
+    }
+
+    #[test]
+    pub fn test_survival_experience() {
+        let mut world = prepare_world();
+        world.meta.current_tick = TICKS_PER_DAY - 1;
+        world.player_mut().action = Some(Action::new(0, Skip {}.into(), &world).unwrap());
+        while world.player().action.is_some() {
+            world.tick();
+        }
+        assert_eq!(
+            Reward::Survival.experience(),
+            world.player().char_sheet.experience
+        );
+    }
+
+    #[test]
+    pub fn test_darkness() {
+        let mut world = prepare_world();
+        assert!(world.is_visible(Point::new(5, 0)));
+
+        // midnight, world starts at 8 AM
+        world.meta.current_tick = 16 * 36_000;
+        world.calc_fov();
+        assert!(world.is_visible(Point::new(1, 0)));
+        assert!(!world.is_visible(Point::new(5, 0)));
+
+        world.player_mut().hands.take_all();
+        world.player_mut().hands.put(Hand::Main, torch());
+        world.calc_fov();
+        assert!(world.is_visible(Point::new(5, 0)));
+        assert!(world.light_level(Point::new(1, 0)) > world.light_level(Point::new(5, 0)));
+        assert!(!world.is_visible(Point::new(8, 0)));
+
+        world.map().get_tile_mut(Point::new(10, 0)).terrain = Terrain::from_id("campfire").unwrap();
+        world.calc_fov();
+        assert!(world.is_visible(Point::new(8, 0)));
+    }
+
+    #[test]
+    pub fn test_weather() {
+        let mut world = prepare_world();
+        let far = Point::new(0, 20);
+        world.map().get_tile_mut(far).terrain = Dirt::default().into();
+        assert!(world.is_visible(far));
+        world.weather = WeatherState::new(Weather::Fog, u128::MAX);
+        world.calc_fov();
+        assert!(!world.is_visible(far));
+
+        let grass = Point::new(1, 0);
+        world.map().get_tile_mut(grass).terrain = Grass::new(GrassVariant::Grass1).into();
+        world.weather = WeatherState::new(Weather::Rain, u128::MAX);
+        for _ in 0..50 {
+            world.apply_weather();
+        }
+        let mut map = world.map();
+        let tile = map.get_tile(Point::new(0, 0));
+        assert_eq!("mud", tile.terrain.name());
+        assert!(matches!(tile.terrain.passage(), Passage::Passable(p) if p > 10.0));
+        drop(map);
+
+        world.weather = WeatherState::new(Weather::Snow, u128::MAX);
+        for _ in 0..50 {
+            world.apply_weather();
+        }
+        assert_eq!(
+            "snow-covered grass",
+            world.map().get_tile(grass).terrain.name()
+        );
+    }
+
+    #[test]
+    pub fn test_same_seed_same_rolls() {
+        let world = prepare_world();
+        let world2 = prepare_world();
+
+        for _ in 0..100 {
+            assert_eq!(
+                Dice::D100.roll(&mut *world.rng()),
+                Dice::D100.roll(&mut *world2.rng())
+            );
+        }
+    }
 }
@@ -1,9 +1,16 @@
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Lines};
+use std::iter::Peekable;
 use std::path::Path;
 
-use crate::game::{map::Chunk, Avatar, World};
+use serde::de::DeserializeOwned;
+
+use crate::game::{
+    map::{Chunk, ChunkMemory},
+    world::rng_from_seed,
+    Avatar, ChunkPos, World,
+};
 
 use super::{Meta, SAVEFILES_FOLDER};
 
@@ -77,10 +84,14 @@ pub fn has_avatar(path: &Path) -> bool {
 
 pub fn load_world(path: &Path) -> Result<World, Error> {
     let file = File::open(path)?;
-    let mut lines = BufReader::new(&file).lines();
-    let meta = lines.next().unwrap()?;
+    let mut lines = BufReader::new(&file).lines().peekable();
+    let meta: Meta = serde_json::from_str(lines.next().unwrap()?.as_str())?;
+    let meta = meta.with_path(path);
     let game_view = lines.next().unwrap()?;
     let log = lines.next().unwrap()?;
+    // saves made before the world had its own RNG continue with the seeded one
+    let rng = optional_line(&mut lines).unwrap_or_else(|| rng_from_seed(&meta.seed));
+    let weather = optional_line(&mut lines).unwrap_or_default();
     let mut units_data = Vec::new();
     loop {
         let unit = lines.next().unwrap()?;
@@ -97,6 +108,8 @@ pub fn load_world(path: &Path) -> Result<World, Error> {
         }
         chunks_data.push(chunk);
     }
+    // saves made before the memory line have none
+    let memory: Vec<(ChunkPos, ChunkMemory)> = optional_line(&mut lines).unwrap_or_default();
 
     let mut units = Vec::with_capacity(units_data.len());
     for unit in &units_data {
@@ -111,10 +124,21 @@ pub fn load_world(path: &Path) -> Result<World, Error> {
     }
 
     Ok(World::new(
-        serde_json::from_str(meta.as_str()).map(|s: Meta| s.with_path(path))?,
+        meta,
         serde_json::from_str(game_view.as_str())?,
         serde_json::from_str(log.as_str())?,
+        rng,
+        weather,
         units,
         chunks,
+        memory.into_iter().collect(),
     ))
 }
+
+/// Lines added to the save format later are missing in older saves,
+/// the next line is consumed only if it is a `T`
+fn optional_line<T: DeserializeOwned, B: BufRead>(lines: &mut Peekable<Lines<B>>) -> Option<T> {
+    let value = serde_json::from_str(lines.peek()?.as_ref().ok()?).ok()?;
+    lines.next();
+    Some(value)
+}
@@ -22,12 +22,23 @@ pub fn delete(path: &Path) {
 mod tests {
     use std::path::PathBuf;
 
-    use crate::game::world::tests::prepare_world;
-    use crate::game::{Dice, SkillLevel};
+    use geometry::Point;
+
+    use crate::game::ai::{implements::Follow, Brain};
+    use crate::game::map::items::helpers::axe;
+    use crate::game::world::{
+        rng_from_seed,
+        tests::{add_npc, prepare_world},
+    };
+    use crate::game::{Dice, SkillLevel, TilePos, WeatherState};
 
     use super::{delete, load, load_world, SAVEFILES_FOLDER};
 
     const SAVEFILE_NAME: &str = "test.save";
+    /// Meta, game view and log go before it
+    const RNG_LINE: usize = 3;
+    const WEATHER_LINE: usize = 4;
+    const PLAYER_LINE: usize = 5;
 
     #[test]
     fn test_save_and_load() {
@@ -39,6 +50,8 @@ mod tests {
         world.player_mut().personality.mind.name = "test".to_string();
         world.player_mut().char_sheet.attributes.agility = Dice::D12;
         world.player_mut().char_sheet.skills.shooting = SkillLevel::D8;
+        let npc = add_npc(&mut world, Point::new(1, 0));
+        world.get_unit_mut(npc).ai = Some(Follow::new(0).into());
         world.save();
 
         let meta = load(&path).unwrap();
@@ -49,10 +62,127 @@ mod tests {
 
         let world2 = load_world(&path).unwrap();
         assert_eq!(world.game_view.zoom, world2.game_view.zoom);
+        assert_eq!(world.weather(), world2.weather());
         assert_eq!(world.player().pos, world2.player().pos);
         assert_eq!("test", world2.player().personality.mind.name);
         assert_eq!(Dice::D12, world2.player().char_sheet.attributes.agility);
         assert_eq!(SkillLevel::D8, world2.player().char_sheet.skills.shooting);
+        assert!(matches!(
+            world2.get_unit(npc).ai,
+            Some(Brain::Follow(Follow { target: 0, .. }))
+        ));
+        for _ in 0..10 {
+            assert_eq!(
+                Dice::D100.roll(&mut *world.rng()),
+                Dice::D100.roll(&mut *world2.rng())
+            );
+        }
+
+        delete(&path);
+    }
+
+    /// Saves made before the RNG line was added
+    #[test]
+    fn test_load_without_rng() {
+        let path = [SAVEFILES_FOLDER, "test_without_rng.save"]
+            .iter()
+            .collect::<PathBuf>();
+        let mut world = prepare_world();
+        world.meta.path = path.clone();
+        world.save();
+        let data = std::fs::read_to_string(&path).unwrap();
+        let mut lines: Vec<&str> = data.lines().collect();
+        lines.remove(RNG_LINE);
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        let world2 = load_world(&path).unwrap();
+        assert_eq!(world.player().pos, world2.player().pos);
+        let mut rng = rng_from_seed(&world.meta.seed);
+        assert_eq!(
+            Dice::D100.roll(&mut rng),
+            Dice::D100.roll(&mut *world2.rng())
+        );
+
+        delete(&path);
+    }
+
+    /// Saves made before the weather line was added, and even before the RNG line
+    #[test]
+    fn test_load_without_weather() {
+        let path = [SAVEFILES_FOLDER, "test_without_weather.save"]
+            .iter()
+            .collect::<PathBuf>();
+        let mut world = prepare_world();
+        world.meta.path = path.clone();
+        world.save();
+        let data = std::fs::read_to_string(&path).unwrap();
+        let mut lines: Vec<&str> = data.lines().collect();
+        lines.remove(WEATHER_LINE);
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        let world2 = load_world(&path).unwrap();
+        assert_eq!(WeatherState::default().current, world2.weather());
+
+        lines.remove(RNG_LINE);
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        let world2 = load_world(&path).unwrap();
+        assert_eq!(WeatherState::default().current, world2.weather());
+        assert_eq!(world.player().pos, world2.player().pos);
+
+        delete(&path);
+    }
+
+    /// Saves made before hands were added have a list of wielded items
+    #[test]
+    fn test_load_wield() {
+        let path = [SAVEFILES_FOLDER, "test_wield.save"]
+            .iter()
+            .collect::<PathBuf>();
+        let mut world = prepare_world();
+        world.meta.path = path.clone();
+        world.save();
+        let data = std::fs::read_to_string(&path).unwrap();
+        let mut lines: Vec<String> = data.lines().map(String::from).collect();
+        let mut player: serde_json::Value = serde_json::from_str(&lines[PLAYER_LINE]).unwrap();
+        let player_data = player.as_object_mut().unwrap();
+        player_data.remove("hands");
+        player_data.insert("wield".to_string(), serde_json::json!([axe()]));
+        lines[PLAYER_LINE] = player.to_string();
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        let world2 = load_world(&path).unwrap();
+        let hands = &world2.player().hands;
+        assert_eq!("axe", hands.main.as_ref().unwrap().proto.id);
+        assert!(hands.off.is_none());
+
+        delete(&path);
+    }
+
+    #[test]
+    fn test_save_memory_apart_from_chunks() {
+        let path = [SAVEFILES_FOLDER, "test_memory.save"]
+            .iter()
+            .collect::<PathBuf>();
+        let mut world = prepare_world();
+        world.meta.path = path.clone();
+        let seen = Point::new(100, 0);
+        world.map().get_tile(seen);
+        world.map().remember_tile(seen);
+        world.save();
+
+        let (chunk, _) = seen.to_chunk();
+        assert!(!world.map().changed.contains(&chunk));
+        let data = std::fs::read_to_string(&path).unwrap();
+        let chunks = data
+            .lines()
+            .skip_while(|line| *line != "/units")
+            .skip(1)
+            .take_while(|line| *line != "/chunks")
+            .count();
+        assert_eq!(world.map().changed.len(), chunks);
+
+        let world2 = load_world(&path).unwrap();
+        assert_eq!(world.map().remembered(seen), world2.map().remembered(seen));
+        assert!(world2.map().remembered(seen).is_some());
 
         delete(&path);
     }
@@ -1,6 +1,9 @@
 use enum_dispatch::enum_dispatch;
 
-use super::implements::{Digging, Dropping, Examining, Observing, Reading, Walking, Wielding};
+use super::implements::{
+    Attacking, Breaking, Climbing, Digging, Dropping, Examining, Filling, Observing, Reading,
+    Targeting, Traveling, Walking, Wielding,
+};
 
 #[enum_dispatch(GameModeImpl)]
 pub enum GameMode {
@@ -11,4 +14,10 @@ pub enum GameMode {
     Digging,
     Observing,
     Reading,
+    Attacking,
+    Traveling,
+    Climbing,
+    Breaking,
+    Filling,
+    Targeting,
 }
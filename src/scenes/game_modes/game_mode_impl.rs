@@ -7,7 +7,10 @@ use crate::game::World;
 
 use super::{
     super::{implements::GameScene, SomeTransitions},
-    implements::{Digging, Dropping, Examining, Observing, Reading, Walking, Wielding},
+    implements::{
+        Attacking, Breaking, Climbing, Digging, Dropping, Examining, Filling, Observing, Reading,
+        Targeting, Traveling, Walking, Wielding,
+    },
     GameMode,
 };
 
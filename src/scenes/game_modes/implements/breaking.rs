@@ -0,0 +1,98 @@
+use geometry::{Direction, Point, DIR8};
+use tetra::{graphics::Color, input::Key, Context};
+
+use crate::{
+    colors::Colors,
+    game::{
+        actions::implements::{Chop, Smash},
+        map::TerrainInteract,
+        ActionType, ItemQuality, World,
+    },
+    input,
+};
+
+use super::super::{
+    super::{implements::GameScene, SomeTransitions},
+    GameModeImpl,
+};
+
+/// Chopping trees and smashing boulders, depending on what is selected
+pub struct Breaking {
+    selected: Option<Direction>,
+}
+
+impl Breaking {
+    pub fn new() -> Self {
+        Self { selected: None }
+    }
+
+    fn can_break(world: &World, dir: Direction) -> bool {
+        let pos = world.player().pos + dir;
+        world
+            .map()
+            .get_tile(pos)
+            .terrain
+            .breaking_quality()
+            .is_some_and(|q| {
+                world
+                    .player()
+                    .hands
+                    .items()
+                    .any(|i| i.qualities().contains(&q))
+            })
+    }
+}
+
+impl Default for Breaking {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameModeImpl for Breaking {
+    fn cursors(&self, world: &World) -> Vec<(Point, Color)> {
+        if let Some(selected) = self.selected {
+            vec![(selected.into(), Colors::LIME)]
+        } else {
+            DIR8.iter()
+                .copied()
+                .filter(|d| Self::can_break(world, *d))
+                .map(|d| (d.into(), Colors::LIGHT_YELLOW))
+                .collect()
+        }
+    }
+
+    fn can_push(&self, world: &World) -> Result<(), String> {
+        if world.player().hands.items().any(|i| {
+            i.qualities().contains(&ItemQuality::Butch)
+                || i.qualities().contains(&ItemQuality::Smash)
+        }) {
+            Ok(())
+        } else {
+            Err("You need an axe or a sledgehammer".to_string())
+        }
+    }
+
+    fn update(&mut self, ctx: &mut Context, game: &mut GameScene) -> SomeTransitions {
+        if input::is_key_pressed(ctx, Key::Escape) {
+            game.modes.pop();
+        } else if let Some(dir) = input::get_direction_keys_down(ctx) {
+            self.selected = Some(dir);
+            game.try_rotate_player(dir);
+        } else if let Some(dir) = self.selected {
+            let typ: ActionType = {
+                let world = game.world.borrow();
+                let pos = world.player().pos + dir;
+                let quality = world.map().get_tile(pos).terrain.breaking_quality();
+                if quality == Some(ItemQuality::Smash) {
+                    Smash { dir }.into()
+                } else {
+                    Chop { dir }.into()
+                }
+            };
+            game.try_start_action(typ);
+            game.modes.pop();
+        }
+        None
+    }
+}
@@ -0,0 +1,68 @@
+use geometry::{Direction, Point, DIR8};
+use tetra::{graphics::Color, input::Key, Context};
+
+use crate::{
+    colors::Colors,
+    game::{actions::implements::Climb, Action, World},
+    input,
+};
+
+use super::super::{
+    super::{implements::GameScene, SomeTransitions},
+    GameModeImpl,
+};
+
+pub struct Climbing {
+    selected: Option<Direction>,
+}
+
+impl Climbing {
+    pub fn new() -> Self {
+        Self { selected: None }
+    }
+}
+
+impl Default for Climbing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameModeImpl for Climbing {
+    fn cursors(&self, world: &World) -> Vec<(Point, Color)> {
+        if let Some(selected) = self.selected {
+            vec![(selected.into(), Colors::LIME)]
+        } else {
+            DIR8.iter()
+                .copied()
+                .filter(|&dir| Action::new(0, Climb { dir }.into(), world).is_ok())
+                .map(|d| (d.into(), Colors::LIGHT_YELLOW))
+                .collect()
+        }
+    }
+
+    fn can_push(&self, world: &World) -> Result<(), String> {
+        if DIR8
+            .iter()
+            .copied()
+            .any(|dir| Action::new(0, Climb { dir }.into(), world).is_ok())
+        {
+            Ok(())
+        } else {
+            Err("There is nothing to climb around".to_string())
+        }
+    }
+
+    fn update(&mut self, ctx: &mut Context, game: &mut GameScene) -> SomeTransitions {
+        if input::is_key_pressed(ctx, Key::Escape) {
+            game.modes.pop();
+        } else if let Some(dir) = input::get_direction_keys_down(ctx) {
+            self.selected = Some(dir);
+            game.try_rotate_player(dir);
+        } else if let Some(dir) = self.selected {
+            game.try_start_action(Climb { dir }.into());
+            game.modes.pop();
+        }
+        None
+    }
+}
@@ -47,8 +47,8 @@ impl GameModeImpl for Digging {
     fn can_push(&self, world: &World) -> Result<(), String> {
         if world
             .player()
-            .wield
-            .iter()
+            .hands
+            .items()
             .any(|i| i.qualities().contains(&ItemQuality::Dig))
         {
             Ok(())
@@ -3,7 +3,7 @@ use tetra::{graphics::Color, input::Key, Context};
 
 use crate::{
     colors::Colors,
-    game::{actions::implements::Drop, map::TerrainInteract, World},
+    game::{actions::implements::Drop, map::TerrainInteract, Hand, World},
     input,
 };
 
@@ -45,7 +45,7 @@ impl GameModeImpl for Dropping {
     }
 
     fn can_push(&self, world: &World) -> Result<(), String> {
-        if world.player().wield.is_empty() {
+        if world.player().hands.is_empty() {
             Err("You have nothing to drop!".to_string())
         } else {
             Ok(())
@@ -59,7 +59,9 @@ impl GameModeImpl for Dropping {
             self.selected = Some(dir);
             game.try_rotate_player(dir);
         } else if let Some(dir) = self.selected {
-            game.try_start_action(Drop { item_id: 0, dir }.into());
+            let hand = game.world.borrow().player().hands.busy_hand();
+            let hand = hand.unwrap_or(Hand::Main);
+            game.try_start_action(Drop { hand, dir }.into());
             game.modes.pop();
         }
         None
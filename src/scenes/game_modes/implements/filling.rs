@@ -0,0 +1,90 @@
+use geometry::{Direction, Point, DIR8};
+use tetra::{graphics::Color, input::Key, Context};
+
+use crate::{
+    colors::Colors,
+    game::{
+        actions::implements::{Bury, Fill},
+        map::Terrain,
+        ActionType, Hand, World,
+    },
+    input,
+};
+
+use super::super::{
+    super::{implements::GameScene, SomeTransitions},
+    GameModeImpl,
+};
+
+/// Filling a pit, optionally burying the held item in it
+pub struct Filling {
+    selected: Option<Direction>,
+    bury: bool,
+}
+
+impl Filling {
+    pub fn new(bury: bool) -> Self {
+        Self {
+            selected: None,
+            bury,
+        }
+    }
+}
+
+impl GameModeImpl for Filling {
+    fn cursors(&self, world: &World) -> Vec<(Point, Color)> {
+        if let Some(selected) = self.selected {
+            vec![(selected.into(), Colors::LIME)]
+        } else {
+            DIR8.iter()
+                .copied()
+                .filter(|d| {
+                    let pos = world.player().pos + *d;
+                    matches!(world.map().get_tile(pos).terrain, Terrain::Pit(..))
+                })
+                .map(|d| (d.into(), Colors::LIGHT_YELLOW))
+                .collect()
+        }
+    }
+
+    fn can_push(&self, world: &World) -> Result<(), String> {
+        if self.bury && world.player().hands.is_empty() {
+            Err("You have nothing to bury".to_string())
+        } else if !DIR8.iter().any(|d| {
+            let player = world.player();
+            Fill::material(player, world, player.pos + *d, None).is_some()
+        }) {
+            Err("You need some soil or stones to fill the pit".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn update(&mut self, ctx: &mut Context, game: &mut GameScene) -> SomeTransitions {
+        if input::is_key_pressed(ctx, Key::Escape) {
+            game.modes.pop();
+        } else if let Some(dir) = input::get_direction_keys_down(ctx) {
+            self.selected = Some(dir);
+            game.try_rotate_player(dir);
+        } else if let Some(dir) = self.selected {
+            let typ: ActionType = if self.bury {
+                // one hand holds the item, other one holds the shovel
+                let world = game.world.borrow();
+                let player = world.player();
+                let hand = [Hand::Main, Hand::Off]
+                    .into_iter()
+                    .find(|h| {
+                        player.hands.get(*h).is_some()
+                            && Fill::material(player, &world, player.pos + dir, Some(*h)).is_some()
+                    })
+                    .unwrap_or(Hand::Main);
+                Bury { hand, dir }.into()
+            } else {
+                Fill { dir }.into()
+            };
+            game.try_start_action(typ);
+            game.modes.pop();
+        }
+        None
+    }
+}
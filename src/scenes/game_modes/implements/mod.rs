@@ -1,15 +1,27 @@
+pub use attacking::Attacking;
+pub use breaking::Breaking;
+pub use climbing::Climbing;
 pub use digging::Digging;
 pub use dropping::Dropping;
 pub use examining::Examining;
+pub use filling::Filling;
 pub use observing::Observing;
 pub use reading::Reading;
+pub use targeting::Targeting;
+pub use traveling::Traveling;
 pub use walking::Walking;
 pub use wielding::Wielding;
 
+mod attacking;
+mod breaking;
+mod climbing;
 mod digging;
 mod dropping;
 mod examining;
+mod filling;
 mod observing;
 mod reading;
+mod targeting;
+mod traveling;
 mod walking;
 mod wielding;
@@ -0,0 +1,142 @@
+use std::time::Instant;
+
+use geometry::Point;
+use tetra::{
+    graphics::Color,
+    input::{Key, KeyModifier},
+    Context,
+};
+
+use crate::{
+    colors::Colors,
+    game::{
+        actions::implements::{Shoot, Throw},
+        map::line,
+        ActionType, Hand, RangedPrototype, World,
+    },
+    input,
+    settings::Settings,
+};
+
+use super::super::{
+    super::{implements::GameScene, SomeTransitions},
+    GameModeImpl,
+};
+
+/// Selecting a target for shooting or throwing
+pub struct Targeting {
+    throwing: bool,
+    last_shift: Instant,
+    /// Relative to the player
+    target: Option<Point>,
+}
+
+impl Targeting {
+    pub fn shooting() -> Self {
+        Self {
+            throwing: false,
+            last_shift: Instant::now(),
+            target: None,
+        }
+    }
+
+    pub fn throwing() -> Self {
+        Self {
+            throwing: true,
+            ..Self::shooting()
+        }
+    }
+
+    fn max_range(&self, world: &World) -> u8 {
+        let hands = &world.player().hands;
+        let ranged = if self.throwing {
+            hands.items().next().and_then(|item| item.proto.throwing)
+        } else {
+            hands.items().find_map(|item| item.proto.shooting)
+        };
+        ranged.map_or(0, RangedPrototype::max_range)
+    }
+
+    /// Nearest noticed unit in range
+    fn nearest_target(&self, world: &World) -> Point {
+        let pos = world.player().pos;
+        let range = u32::from(self.max_range(world));
+        world
+            .visible_units()
+            .into_iter()
+            .map(|i| world.get_unit(i).pos)
+            .filter(|p| p.square_distance(pos) <= range * range)
+            .min_by_key(|p| (p.square_distance(pos), p.x, p.y))
+            .map_or(Point::default(), |p| Point::new(p.x - pos.x, p.y - pos.y))
+    }
+
+    fn action(&self, world: &World, target: Point) -> ActionType {
+        let target = world.player().pos + target;
+        if self.throwing {
+            let hand = world.player().hands.busy_hand().unwrap_or(Hand::Main);
+            Throw { hand, target }.into()
+        } else {
+            Shoot { target }.into()
+        }
+    }
+}
+
+impl GameModeImpl for Targeting {
+    fn cursors(&self, world: &World) -> Vec<(Point, Color)> {
+        let Some(target) = self.target else {
+            return vec![];
+        };
+        let mut cursors: Vec<(Point, Color)> = line(Point::default(), target)
+            .into_iter()
+            .skip(1)
+            .map(|p| (p, Colors::YELLOW.with_alpha(0.5)))
+            .collect();
+        let pos = world.player().pos + target;
+        let color = if world.map().get_tile(pos).units.is_empty() {
+            Colors::LIME
+        } else {
+            Colors::RED
+        };
+        cursors.pop();
+        cursors.push((target, color));
+
+        cursors
+    }
+
+    fn can_push(&self, world: &World) -> Result<(), String> {
+        if self.max_range(world) > 0 {
+            Ok(())
+        } else if self.throwing {
+            Err("You have nothing to throw".to_string())
+        } else {
+            Err("You have nothing to shoot with".to_string())
+        }
+    }
+
+    fn update(&mut self, ctx: &mut Context, game: &mut GameScene) -> SomeTransitions {
+        if self.target.is_none() {
+            self.target = Some(self.nearest_target(&game.world.borrow()));
+        }
+        let target = self.target.unwrap_or_default();
+        if input::is_key_pressed(ctx, Key::Escape) {
+            game.modes.pop();
+        } else if input::is_key_with_mod_pressed(ctx, Key::Enter)
+            || input::is_key_with_mod_pressed(ctx, Key::F)
+        {
+            let typ = self.action(&game.world.borrow(), target);
+            game.try_start_action(typ);
+            game.modes.pop();
+        } else if let Some(dir) = input::get_direction_keys_down(ctx) {
+            let now = Instant::now();
+            if now.duration_since(self.last_shift).subsec_millis()
+                > Settings::instance().input.repeat_interval
+                || input::is_key_modifier_down(ctx, KeyModifier::Shift)
+            {
+                self.last_shift = now;
+                self.target = Some(target + dir);
+            }
+        }
+
+        None
+    }
+}
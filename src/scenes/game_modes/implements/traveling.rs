@@ -0,0 +1,160 @@
+use std::collections::{HashSet, VecDeque};
+use std::time::Instant;
+
+use geometry::{Direction, Point, Vec2};
+use tetra::{
+    graphics::Color,
+    input::{Key, KeyModifier, MouseButton},
+    Context,
+};
+
+use crate::{
+    colors::Colors,
+    game::{actions::implements::Walk, map::find_path, World},
+    input,
+    settings::Settings,
+};
+
+use super::super::{
+    super::{implements::GameScene, SomeTransitions},
+    GameModeImpl,
+};
+
+pub struct Traveling {
+    last_shift: Instant,
+    last_mouse_position: Vec2,
+    mouse_moved: bool,
+    /// Target tile relative to player
+    cursor: Point,
+    /// Remaining steps, `None` while target is not selected
+    path: Option<VecDeque<Direction>>,
+    seen_units: HashSet<usize>,
+}
+
+impl Traveling {
+    pub fn new() -> Self {
+        Self {
+            last_shift: Instant::now(),
+            last_mouse_position: Vec2::zero(),
+            mouse_moved: false,
+            cursor: Point::default(),
+            path: None,
+            seen_units: HashSet::new(),
+        }
+    }
+
+    fn update_mouse(&mut self, ctx: &mut Context, game: &mut GameScene) {
+        let mouse = input::get_mouse_position(ctx);
+        if mouse != self.last_mouse_position {
+            self.last_mouse_position = mouse;
+            if self.mouse_moved {
+                let (w, h) = game.window_size;
+                self.cursor =
+                    ((mouse - Vec2::new((w / 2) as f32, (h / 2) as f32)) / game.tile_size()).into();
+            }
+            self.mouse_moved = true;
+        }
+    }
+
+    fn select(&mut self, ctx: &mut Context, game: &mut GameScene) {
+        self.update_mouse(ctx, game);
+        if input::is_key_pressed(ctx, Key::Escape) {
+            game.modes.pop();
+        } else if input::is_key_pressed(ctx, Key::Enter)
+            || input::is_mouse_button_pressed(ctx, MouseButton::Left)
+        {
+            let world = game.world.borrow();
+            let from = world.player().pos;
+            let path = find_path(&mut world.map(), from, from + self.cursor);
+            self.seen_units = world.visible_units();
+            drop(world);
+            match path {
+                Some(path) if !path.is_empty() => self.path = Some(path.into()),
+                _ => game
+                    .log
+                    .log("You can't find a way there", Colors::LIGHT_CORAL),
+            }
+        } else if let Some(dir) = input::get_direction_keys_down(ctx) {
+            let now = Instant::now();
+            if now.duration_since(self.last_shift).subsec_millis()
+                > Settings::instance().input.repeat_interval
+                || input::is_key_modifier_down(ctx, KeyModifier::Shift)
+            {
+                self.last_shift = now;
+                self.cursor += dir;
+            }
+        }
+    }
+
+    /// Calls every time player finished previous step
+    fn travel(&mut self, ctx: &mut Context, game: &mut GameScene) {
+        if input::is_key_pressed(ctx, Key::Escape) {
+            game.modes.pop();
+            return;
+        }
+
+        let visible_units = game.world.borrow().visible_units();
+        let new_units: Vec<String> = visible_units
+            .difference(&self.seen_units)
+            .map(|&i| {
+                game.world
+                    .borrow()
+                    .get_unit(i)
+                    .personality
+                    .mind
+                    .name
+                    .clone()
+            })
+            .collect();
+        if !new_units.is_empty() {
+            game.log.log(
+                format!("You see {} and stop", new_units.join(", ")),
+                Colors::LIGHT_YELLOW,
+            );
+            game.modes.pop();
+            return;
+        }
+        // units can leave Fov and come back
+        self.seen_units = visible_units;
+
+        if let Some(dir) = self.path.as_mut().and_then(VecDeque::pop_front) {
+            game.try_rotate_player(dir);
+            game.try_start_action(Walk { dir }.into());
+            if game.world.borrow().player().action.is_some() {
+                return;
+            }
+        }
+        game.modes.pop();
+    }
+}
+
+impl Default for Traveling {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameModeImpl for Traveling {
+    fn cursors(&self, _world: &World) -> Vec<(Point, Color)> {
+        if let Some(path) = &self.path {
+            let mut pos = Point::default();
+            path.iter()
+                .map(|&dir| {
+                    pos += dir;
+                    (pos, Colors::LIGHT_YELLOW)
+                })
+                .collect()
+        } else {
+            vec![(self.cursor, Colors::LIME)]
+        }
+    }
+
+    fn update(&mut self, ctx: &mut Context, game: &mut GameScene) -> SomeTransitions {
+        if self.path.is_some() {
+            self.travel(ctx, game);
+        } else {
+            self.select(ctx, game);
+        }
+        None
+    }
+}
@@ -7,15 +7,20 @@ use tetra::{
 };
 
 use crate::{
-    colors::Colors,
-    game::actions::implements::{Drop, Skip, Walk},
+    game::{
+        actions::implements::{Drop, Skip},
+        ActionType, Hand,
+    },
     input,
     settings::Settings,
 };
 
 use super::super::{
     super::{implements::GameScene, Scene, SomeTransitions, Transition},
-    implements::{Digging, Dropping, Examining, Observing, Reading, Wielding},
+    implements::{
+        Attacking, Breaking, Climbing, Digging, Dropping, Examining, Filling, Observing, Reading,
+        Targeting, Traveling, Wielding,
+    },
     GameModeImpl,
 };
 
@@ -53,9 +58,11 @@ impl GameModeImpl for Walking {
             game.push_mode(Examining::new().into());
             None
         } else if input::is_key_with_mod_pressed(ctx, Key::D) {
+            let hand = game.world.borrow().player().hands.busy_hand();
+            let hand = hand.unwrap_or(Hand::Main);
             game.try_start_action(
                 Drop {
-                    item_id: 0,
+                    hand,
                     dir: Direction::Here,
                 }
                 .into(),
@@ -73,29 +80,43 @@ impl GameModeImpl for Walking {
         } else if input::is_key_with_mod_pressed(ctx, Key::G) {
             game.push_mode(Digging::new().into());
             None
+        } else if input::is_key_with_mod_pressed(ctx, (Key::G, KeyModifier::Shift)) {
+            game.push_mode(Filling::new(false).into());
+            None
+        } else if input::is_key_with_mod_pressed(ctx, Key::C) {
+            game.push_mode(Climbing::new().into());
+            None
+        } else if input::is_key_with_mod_pressed(ctx, Key::B) {
+            game.push_mode(Breaking::new().into());
+            None
+        } else if input::is_key_with_mod_pressed(ctx, (Key::B, KeyModifier::Shift)) {
+            game.push_mode(Filling::new(true).into());
+            None
+        } else if input::is_key_with_mod_pressed(ctx, Key::F) {
+            game.push_mode(Attacking::new().into());
+            None
+        } else if input::is_key_with_mod_pressed(ctx, (Key::F, KeyModifier::Shift)) {
+            game.push_mode(Targeting::shooting().into());
+            None
+        } else if input::is_key_with_mod_pressed(ctx, Key::T) {
+            game.push_mode(Traveling::new().into());
+            None
+        } else if input::is_key_with_mod_pressed(ctx, (Key::T, KeyModifier::Shift)) {
+            game.push_mode(Targeting::throwing().into());
+            None
         } else if input::is_key_with_mod_pressed(ctx, Key::X) {
             game.push_mode(Observing::new().into());
             None
         } else if input::is_key_with_mod_pressed(ctx, (Key::R, KeyModifier::Shift)) {
             game.push_mode(Reading::new().into());
             None
-            // } else if input::is_key_with_mod_pressed(ctx, (Key::Num2, KeyModifier::Shift)) {
-            //     Some(vec![Transition::Push(Scene::BodyView(0))])
-        } else if input::is_key_with_mod_pressed(ctx, Key::I) {
-            // TODO: inventory game scene
-            let items: Vec<String> = game
-                .world
-                .borrow()
-                .player()
-                .wear
-                .iter()
-                .map(|i| i.name().to_string())
-                .collect();
-            game.log.log(
-                format!("You wear: {}", items.join(", ")),
-                Colors::WHITE_SMOKE,
-            );
+        } else if input::is_key_with_mod_pressed(ctx, Key::L) {
+            game.log.toggle_details();
             None
+        } else if input::is_key_with_mod_pressed(ctx, (Key::Num2, KeyModifier::Shift)) {
+            Some(vec![Transition::Push(Scene::CharacterSheet)])
+        } else if input::is_key_with_mod_pressed(ctx, Key::I) {
+            Some(vec![Transition::Push(Scene::Inventory)])
         } else if let Some(dir) = input::get_direction_keys_down(ctx) {
             let now = Instant::now();
             if now.duration_since(self.last_walk).subsec_millis()
@@ -107,7 +128,11 @@ impl GameModeImpl for Walking {
                     game.try_start_action(Skip {}.into());
                 } else {
                     game.try_rotate_player(dir);
-                    game.try_start_action(Walk { dir }.into());
+                    let typ = {
+                        let world = game.world.borrow();
+                        ActionType::movement(dir, world.player(), &world)
+                    };
+                    game.try_start_action(typ);
                 }
             }
             None
@@ -45,14 +45,14 @@ impl GameModeImpl for Wielding {
     }
 
     fn can_push(&self, world: &World) -> Result<(), String> {
-        // TODO: hands counting
-        if world.player().wield.is_empty() {
-            Ok(())
-        } else {
+        let hands = &world.player().hands;
+        if hands.is_full() {
             Err(format!(
                 "You already have {} in hands",
-                world.player().wield.last().unwrap().name()
+                hands.display_name()
             ))
+        } else {
+            Ok(())
         }
     }
 
@@ -1,14 +1,13 @@
 use std::collections::HashMap;
 use std::path::Path;
 
-use geometry::Point;
 use tetra::graphics::Color;
 use tetra::{Context, Event};
 
 use crate::{
     app::App,
     colors::Colors,
-    game::{races::Personality, traits::Name, Avatar, CharSheet, Dice, SkillLevel, World},
+    game::{races::Personality, traits::Name, CharSheet, Dice, SkillLevel},
     savefile::{self, Meta},
     scenes::{
         helpers::{
@@ -101,7 +100,7 @@ impl CharacterAttributes {
             ctx,
             ButtonEvent::Randomize as u8,
             ButtonEvent::Next as u8,
-            "Create character",
+            "Next step",
         );
         let mut char_sheet = CharSheet::default(personality.appearance.race);
         for (skill, level) in personality.appearance.race.free_skills() {
@@ -1057,7 +1056,8 @@ impl CharacterAttributes {
     }
 
     fn randomize(&mut self, ctx: &mut Context) -> SomeTransitions {
-        self.char_sheet = CharSheet::random(self.personality.appearance.race);
+        let mut rng = rand::thread_rng();
+        self.char_sheet = CharSheet::random(&mut rng, self.personality.appearance.race);
         self.attributes_points = 0;
         let window_size = self.window_size;
         let agility = self.char_sheet.attributes.agility.name();
@@ -1075,20 +1075,11 @@ impl CharacterAttributes {
     }
 
     fn next(&self) -> Vec<Transition> {
-        // TODO: traits, skills, etc.
-        // TODO: find available starting pos in the world
-        let avatar = Avatar::dressed_default(
+        vec![Transition::Push(Scene::CharacterEdges(
+            self.meta.path.clone(),
             self.personality.clone(),
             self.char_sheet.clone(),
-            Point::new(0, 0),
-        );
-        let mut world = World::create(self.meta.clone(), avatar).init();
-        world.save();
-
-        vec![
-            Transition::LoadWorld(self.meta.path.clone()),
-            Transition::Replace(Scene::GameScene),
-        ]
+        ))]
     }
 }
 
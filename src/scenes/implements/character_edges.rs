@@ -0,0 +1,428 @@
+use std::path::Path;
+
+use geometry::Point;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use tetra::graphics::Color;
+use tetra::{Context, Event};
+
+use crate::{
+    app::App,
+    colors::Colors,
+    game::{
+        races::Personality, traits::Name, Avatar, CharSheet, EdgePrototype, GameData, World,
+        EDGE_COST, MAX_HINDRANCE_POINTS,
+    },
+    savefile::{self, Meta},
+    scenes::{
+        helpers::{
+            back_randomize_next, bg, colored_label, decorative_label, easy_back, icon_minus,
+            icon_plus, title,
+        },
+        Scene, SceneImpl, SomeTransitions, Transition,
+    },
+    ui::{
+        Alert, Colorize, Disable, Horizontal, Label, Position, SomeUISprites, SomeUISpritesMut,
+        UiSprite, Vertical,
+    },
+};
+
+const RANDOMIZE: u8 = 0;
+const NEXT: u8 = 1;
+/// Every row has two events: drop and take
+const FIRST_ROW_EVENT: u8 = 2;
+
+const ROW_HEIGHT: f32 = 60.0;
+const FIRST_ROW_Y: f32 = 260.0;
+const COLUMN_WIDTH: f32 = 540.0;
+const HINDRANCES_COLUMN: f32 = -280.0;
+const EDGES_COLUMN: f32 = 280.0;
+
+const TAKEN_COLOR: Color = Colors::DARK_GREEN;
+const NOT_TAKEN_COLOR: Color = Colors::DARK_BROWN;
+
+type Sprites = Vec<Box<dyn UiSprite>>;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Kind {
+    Hindrance,
+    Edge,
+}
+
+/// Indexes of row's sprites
+struct Row {
+    kind: Kind,
+    id: String,
+    minus: usize,
+    name: usize,
+    plus: usize,
+}
+
+pub struct CharacterEdges {
+    meta: Meta,
+    personality: Personality,
+    char_sheet: CharSheet,
+    rows: Vec<Row>,
+    points_label: usize,
+    window_size: (i32, i32),
+    sprites: Sprites,
+}
+
+impl CharacterEdges {
+    pub fn new(
+        path: &Path,
+        personality: Personality,
+        char_sheet: CharSheet,
+        app: &App,
+        ctx: &mut Context,
+    ) -> Self {
+        let meta = savefile::load(path).unwrap();
+        let data = GameData::instance();
+        let mut hindrances: Vec<_> = data.hindrances.values().collect();
+        hindrances.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut edges: Vec<_> = data.edges.values().collect();
+        edges.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut sprites: Sprites = vec![
+            bg(&app.assets),
+            title(
+                format!("Choose edges & hindrances of {}", personality.mind.name),
+                &app.assets,
+            ),
+            decorative_label(
+                "",
+                &app.assets,
+                Position {
+                    x: Horizontal::AtWindowCenterByCenter { offset: 0.0 },
+                    y: Vertical::ByCenter { y: 150.0 },
+                },
+                Colors::DARK_BROWN,
+            ),
+        ];
+        let points_label = sprites.len() - 1;
+        let rows_count = hindrances.len().max(edges.len());
+        for (column, header) in [(HINDRANCES_COLUMN, "Hindrances"), (EDGES_COLUMN, "Edges")] {
+            sprites.push(Box::new(Alert::passive(
+                COLUMN_WIDTH,
+                rows_count as f32 * ROW_HEIGHT + 80.0,
+                app.assets.alert.clone(),
+                Position {
+                    x: Horizontal::AtWindowCenterByCenter { offset: column },
+                    y: Vertical::ByTop { y: 180.0 },
+                },
+            )));
+            sprites.push(decorative_label(
+                header,
+                &app.assets,
+                Position {
+                    x: Horizontal::AtWindowCenterByCenter { offset: column },
+                    y: Vertical::ByTop { y: 190.0 },
+                },
+                Colors::DARK_BROWN,
+            ));
+        }
+
+        let mut rows = Vec::with_capacity(hindrances.len() + edges.len());
+        for (i, hindrance) in hindrances.iter().enumerate() {
+            rows.push(Self::push_row(
+                &mut sprites,
+                app,
+                (Kind::Hindrance, hindrance.id.as_str()),
+                &hindrance.name,
+                format!("{} ({})", hindrance.description, hindrance.severity.name()),
+                (HINDRANCES_COLUMN, i),
+                rows.len(),
+            ));
+        }
+        for (i, edge) in edges.iter().enumerate() {
+            rows.push(Self::push_row(
+                &mut sprites,
+                app,
+                (Kind::Edge, edge.id.as_str()),
+                &edge.name,
+                edge_description(edge),
+                (EDGES_COLUMN, i),
+                rows.len(),
+            ));
+        }
+
+        let (back_btn, randomize_btn, next_btn) =
+            back_randomize_next(&app.assets, ctx, RANDOMIZE, NEXT, "Create character");
+        sprites.push(back_btn);
+        sprites.push(randomize_btn);
+        sprites.push(next_btn);
+
+        Self {
+            meta,
+            personality,
+            char_sheet,
+            rows,
+            points_label,
+            window_size: app.window_size,
+            sprites,
+        }
+    }
+
+    /// Row `i` of the column with the `column` offset,
+    /// `n` is the index of the row used for its events
+    fn push_row(
+        sprites: &mut Sprites,
+        app: &App,
+        (kind, id): (Kind, &str),
+        name: &str,
+        description: String,
+        (column, i): (f32, usize),
+        n: usize,
+    ) -> Row {
+        let y = FIRST_ROW_Y + i as f32 * ROW_HEIGHT;
+        let event = FIRST_ROW_EVENT + n as u8 * 2;
+        sprites.push(icon_minus(
+            &app.assets,
+            Position {
+                x: Horizontal::AtWindowCenterByCenter {
+                    offset: column - COLUMN_WIDTH / 2.0 + 40.0,
+                },
+                y: Vertical::ByCenter { y },
+            },
+            event,
+        ));
+        let minus = sprites.len() - 1;
+        sprites.push(colored_label(
+            name,
+            &app.assets,
+            Position {
+                x: Horizontal::AtWindowCenterByLeft {
+                    offset: column - COLUMN_WIDTH / 2.0 + 75.0,
+                },
+                y: Vertical::ByCenter { y: y - 10.0 },
+            },
+            NOT_TAKEN_COLOR,
+        ));
+        let name = sprites.len() - 1;
+        sprites.push(Box::new(Label::new(
+            description,
+            app.assets.fonts.default.clone(),
+            Colors::DARK_BROWN,
+            Position {
+                x: Horizontal::AtWindowCenterByLeft {
+                    offset: column - COLUMN_WIDTH / 2.0 + 75.0,
+                },
+                y: Vertical::ByCenter { y: y + 14.0 },
+            },
+        )));
+        sprites.push(icon_plus(
+            &app.assets,
+            Position {
+                x: Horizontal::AtWindowCenterByCenter {
+                    offset: column + COLUMN_WIDTH / 2.0 - 40.0,
+                },
+                y: Vertical::ByCenter { y },
+            },
+            event + 1,
+        ));
+        let plus = sprites.len() - 1;
+
+        Row {
+            kind,
+            id: id.to_string(),
+            minus,
+            name,
+            plus,
+        }
+    }
+
+    /// Edges which can be taken for Hindrance points
+    fn edges_left(&self) -> u8 {
+        (self.char_sheet.hindrance_points() / EDGE_COST)
+            .saturating_sub(self.char_sheet.edges.len() as u8)
+    }
+
+    fn taken(&self, kind: Kind, id: &str) -> bool {
+        match kind {
+            Kind::Hindrance => self.char_sheet.hindrances.iter().any(|h| h == id),
+            Kind::Edge => self.char_sheet.edges.iter().any(|e| e == id),
+        }
+    }
+
+    fn can_take(&self, kind: Kind, id: &str) -> bool {
+        match kind {
+            Kind::Hindrance => !self.taken(kind, id),
+            Kind::Edge => {
+                self.edges_left() > 0
+                    && self
+                        .char_sheet
+                        .can_take_edge(self.personality.appearance.race, id)
+            }
+        }
+    }
+
+    fn take(&mut self, kind: Kind, id: &str) {
+        if !self.can_take(kind, id) {
+            return;
+        }
+        match kind {
+            Kind::Hindrance => self.char_sheet.hindrances.push(id.to_string()),
+            Kind::Edge => self.char_sheet.edges.push(id.to_string()),
+        }
+    }
+
+    fn drop(&mut self, kind: Kind, id: &str) {
+        match kind {
+            Kind::Hindrance => {
+                self.char_sheet.hindrances.retain(|h| h != id);
+                // there could be not enough points for taken Edges now
+                let max_edges = (self.char_sheet.hindrance_points() / EDGE_COST) as usize;
+                self.char_sheet.edges.truncate(max_edges);
+            }
+            Kind::Edge => self.char_sheet.edges.retain(|e| e != id),
+        }
+    }
+
+    fn update_rows(&mut self, ctx: &mut Context) {
+        let window_size = self.window_size;
+        let text = format!(
+            "Hindrance points: {}/{MAX_HINDRANCE_POINTS}, edges left: {}",
+            self.char_sheet.hindrance_points(),
+            self.edges_left()
+        );
+        self.sprites[self.points_label]
+            .as_label()
+            .unwrap()
+            .update(text, ctx, window_size);
+
+        let states: Vec<(bool, bool)> = self
+            .rows
+            .iter()
+            .map(|row| {
+                (
+                    self.taken(row.kind, &row.id),
+                    self.can_take(row.kind, &row.id),
+                )
+            })
+            .collect();
+        for (row, (taken, can_take)) in self.rows.iter().zip(states) {
+            self.sprites[row.minus]
+                .as_button()
+                .unwrap()
+                .set_disabled(!taken);
+            self.sprites[row.plus]
+                .as_button()
+                .unwrap()
+                .set_disabled(!can_take);
+            self.sprites[row.name]
+                .as_label()
+                .unwrap()
+                .set_color(if taken { TAKEN_COLOR } else { NOT_TAKEN_COLOR });
+        }
+    }
+
+    /// Random Hindrances and as many Edges as they pay for
+    fn randomize(&mut self) {
+        let mut rng = rand::thread_rng();
+        self.char_sheet.hindrances.clear();
+        self.char_sheet.edges.clear();
+        let mut rows: Vec<(Kind, String)> = self
+            .rows
+            .iter()
+            .map(|row| (row.kind, row.id.clone()))
+            .collect();
+        rows.shuffle(&mut rng);
+        let points = rng.gen_range(0..=MAX_HINDRANCE_POINTS);
+        for (kind, id) in &rows {
+            if *kind == Kind::Hindrance && self.char_sheet.hindrance_points() < points {
+                self.take(*kind, id);
+            }
+        }
+        for (kind, id) in &rows {
+            if *kind == Kind::Edge {
+                self.take(*kind, id);
+            }
+        }
+    }
+
+    fn next(&self) -> Vec<Transition> {
+        let avatar = Avatar::dressed_default(
+            self.personality.clone(),
+            self.char_sheet.clone(),
+            Point::new(0, 0),
+        );
+        let mut world = World::create(self.meta.clone(), avatar).init();
+        world.save();
+
+        vec![
+            Transition::LoadWorld(self.meta.path.clone()),
+            Transition::Replace(Scene::GameScene),
+        ]
+    }
+}
+
+/// Description with requirements, like `Moves faster, requires Agility d8`
+pub(super) fn edge_description(edge: &EdgePrototype) -> String {
+    let requirements = &edge.requirements;
+    let mut list: Vec<String> = requirements
+        .races
+        .iter()
+        .map(|race| race.name().to_string())
+        .collect();
+    let mut attributes: Vec<String> = requirements
+        .attributes
+        .iter()
+        .map(|(attribute, dice)| format!("{} {}", attribute.name(), dice.name()))
+        .collect();
+    attributes.sort();
+    let mut skills: Vec<String> = requirements
+        .skills
+        .iter()
+        .map(|(skill, level)| format!("{} {}", skill.name(), level.name()))
+        .collect();
+    skills.sort();
+    list.extend(attributes);
+    list.extend(skills);
+
+    if list.is_empty() {
+        edge.description.clone()
+    } else {
+        format!("{}, requires {}", edge.description, list.join(", "))
+    }
+}
+
+impl SceneImpl for CharacterEdges {
+    fn event(&mut self, _ctx: &mut Context, event: Event) -> SomeTransitions {
+        easy_back(&event, false)
+    }
+
+    fn on_open(&mut self, ctx: &mut Context) {
+        self.update_rows(ctx);
+    }
+
+    fn on_resize(&mut self, _ctx: &mut Context, window_size: (i32, i32)) {
+        self.window_size = window_size;
+    }
+
+    fn sprites(&self) -> SomeUISprites {
+        Some(&self.sprites)
+    }
+
+    fn sprites_mut(&mut self) -> SomeUISpritesMut {
+        Some(&mut self.sprites)
+    }
+
+    fn custom_event(&mut self, ctx: &mut Context, event: u8) -> SomeTransitions {
+        match event {
+            RANDOMIZE => self.randomize(),
+            NEXT => return Some(self.next()),
+            _ => {
+                let n = ((event - FIRST_ROW_EVENT) / 2) as usize;
+                let row = self.rows.get(n)?;
+                let (kind, id) = (row.kind, row.id.clone());
+                if (event - FIRST_ROW_EVENT) % 2 == 0 {
+                    self.drop(kind, &id);
+                } else {
+                    self.take(kind, &id);
+                }
+            }
+        }
+        self.update_rows(ctx);
+        None
+    }
+}
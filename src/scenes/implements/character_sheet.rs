@@ -0,0 +1,358 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use tetra::graphics::Color;
+use tetra::{Context, Event};
+
+use crate::{
+    app::App,
+    colors::Colors,
+    game::{traits::Name, Advance, Attribute, GameData, World, ADVANCE_POINTS},
+    scenes::{
+        helpers::{back_btn, bg, colored_label, decorative_label, easy_back, icon_plus, title},
+        SceneImpl, SomeTransitions,
+    },
+    ui::{
+        Alert, Colorize, Disable, Horizontal, Label, Position, SomeUISprites, SomeUISpritesMut,
+        UiSprite, Vertical,
+    },
+};
+
+use super::character_edges::edge_description;
+
+const ROW_HEIGHT: f32 = 36.0;
+const EDGE_ROW_HEIGHT: f32 = 50.0;
+const FIRST_ROW_Y: f32 = 250.0;
+const COLUMN_WIDTH: f32 = 400.0;
+const ATTRIBUTES_COLUMN: f32 = -420.0;
+const SKILLS_COLUMN: f32 = 0.0;
+const EDGES_COLUMN: f32 = 420.0;
+
+const TAKEN_COLOR: Color = Colors::DARK_GREEN;
+const NOT_TAKEN_COLOR: Color = Colors::DARK_BROWN;
+
+const ATTRIBUTES: [Attribute; 5] = [
+    Attribute::Agility,
+    Attribute::Smarts,
+    Attribute::Spirit,
+    Attribute::Strength,
+    Attribute::Vigor,
+];
+
+type Sprites = Vec<Box<dyn UiSprite>>;
+
+/// Indexes of row's sprites, the event of the row is its index
+struct Row {
+    advance: Advance,
+    name: usize,
+    cost: usize,
+    plus: usize,
+}
+
+/// Player spends Advances here
+pub struct CharacterSheet {
+    world: Rc<RefCell<World>>,
+    rows: Vec<Row>,
+    points_label: usize,
+    hindrances_label: usize,
+    window_size: (i32, i32),
+    sprites: Sprites,
+}
+
+impl CharacterSheet {
+    pub fn new(app: &App) -> Self {
+        let world = app.get_world();
+        let name = world.borrow().player().personality.mind.name.clone();
+        let data = GameData::instance();
+        let mut edges: Vec<_> = data.edges.values().collect();
+        edges.sort_by(|a, b| a.name.cmp(&b.name));
+        let skills = world
+            .borrow()
+            .player()
+            .char_sheet
+            .skills
+            .get_skills_by_attributes();
+
+        let mut sprites: Sprites = vec![
+            bg(&app.assets),
+            title(format!("Character sheet of {name}"), &app.assets),
+            decorative_label(
+                "",
+                &app.assets,
+                Position {
+                    x: Horizontal::AtWindowCenterByCenter { offset: 0.0 },
+                    y: Vertical::ByCenter { y: 150.0 },
+                },
+                Colors::DARK_BROWN,
+            ),
+        ];
+        let points_label = sprites.len() - 1;
+        let columns = [
+            (
+                ATTRIBUTES_COLUMN,
+                "Attributes",
+                ATTRIBUTES.len() as f32 * ROW_HEIGHT + 120.0,
+            ),
+            (
+                SKILLS_COLUMN,
+                "Skills",
+                skills.len() as f32 * ROW_HEIGHT + 60.0,
+            ),
+            (
+                EDGES_COLUMN,
+                "Edges",
+                edges.len() as f32 * EDGE_ROW_HEIGHT + 60.0,
+            ),
+        ];
+        for (column, header, height) in columns {
+            Self::push_column(&mut sprites, app, column, header, height);
+        }
+
+        // Hindrances can't be changed, they are listed under the attributes
+        sprites.push(Box::new(Label::new(
+            "",
+            app.assets.fonts.default.clone(),
+            Colors::DARK_BROWN,
+            Position {
+                x: Horizontal::AtWindowCenterByLeft {
+                    offset: ATTRIBUTES_COLUMN - COLUMN_WIDTH / 2.0 + 20.0,
+                },
+                y: Vertical::ByCenter {
+                    y: FIRST_ROW_Y + ATTRIBUTES.len() as f32 * ROW_HEIGHT + 20.0,
+                },
+            },
+        )));
+        let hindrances_label = sprites.len() - 1;
+        let attributes = ATTRIBUTES.into_iter().enumerate().map(|(i, attribute)| {
+            let y = FIRST_ROW_Y + i as f32 * ROW_HEIGHT;
+            (Advance::Attribute(attribute), None, (ATTRIBUTES_COLUMN, y))
+        });
+        let skills = skills.into_iter().enumerate().map(|(i, (_, skill, _))| {
+            let y = FIRST_ROW_Y + i as f32 * ROW_HEIGHT;
+            (Advance::Skill(skill), None, (SKILLS_COLUMN, y))
+        });
+        let edges = edges.into_iter().enumerate().map(|(i, edge)| {
+            let y = FIRST_ROW_Y + i as f32 * EDGE_ROW_HEIGHT;
+            let description = Some(edge_description(edge));
+            (
+                Advance::Edge(edge.id.as_str()),
+                description,
+                (EDGES_COLUMN, y),
+            )
+        });
+        let rows = attributes
+            .chain(skills)
+            .chain(edges)
+            .enumerate()
+            .map(|(n, (advance, description, position))| {
+                Self::push_row(&mut sprites, app, advance, description, position, n)
+            })
+            .collect();
+
+        sprites.push(back_btn(
+            Position::horizontal_center(0.0, Vertical::AtWindowBottomByBottom { offset: -50.0 }),
+            &app.assets,
+        ));
+
+        Self {
+            world,
+            rows,
+            points_label,
+            hindrances_label,
+            window_size: app.window_size,
+            sprites,
+        }
+    }
+
+    fn push_column(sprites: &mut Sprites, app: &App, column: f32, header: &str, height: f32) {
+        sprites.push(Box::new(Alert::passive(
+            COLUMN_WIDTH,
+            height,
+            app.assets.alert.clone(),
+            Position {
+                x: Horizontal::AtWindowCenterByCenter { offset: column },
+                y: Vertical::ByTop { y: 180.0 },
+            },
+        )));
+        sprites.push(decorative_label(
+            header,
+            &app.assets,
+            Position {
+                x: Horizontal::AtWindowCenterByCenter { offset: column },
+                y: Vertical::ByTop { y: 190.0 },
+            },
+            Colors::DARK_BROWN,
+        ));
+    }
+
+    /// Row at `y` of the column with the `column` offset, `n` is the event of the row
+    fn push_row(
+        sprites: &mut Sprites,
+        app: &App,
+        advance: Advance,
+        description: Option<String>,
+        (column, y): (f32, f32),
+        n: usize,
+    ) -> Row {
+        let name_y = if description.is_some() { y - 10.0 } else { y };
+        sprites.push(colored_label(
+            "",
+            &app.assets,
+            Position {
+                x: Horizontal::AtWindowCenterByLeft {
+                    offset: column - COLUMN_WIDTH / 2.0 + 20.0,
+                },
+                y: Vertical::ByCenter { y: name_y },
+            },
+            NOT_TAKEN_COLOR,
+        ));
+        let name = sprites.len() - 1;
+        if let Some(description) = description {
+            sprites.push(Box::new(Label::new(
+                description,
+                app.assets.fonts.default.clone(),
+                Colors::DARK_BROWN,
+                Position {
+                    x: Horizontal::AtWindowCenterByLeft {
+                        offset: column - COLUMN_WIDTH / 2.0 + 20.0,
+                    },
+                    y: Vertical::ByCenter { y: y + 14.0 },
+                },
+            )));
+        }
+        sprites.push(Box::new(Label::new(
+            "",
+            app.assets.fonts.default.clone(),
+            Colors::DARK_BROWN,
+            Position {
+                x: Horizontal::AtWindowCenterByRight {
+                    offset: column + COLUMN_WIDTH / 2.0 - 70.0,
+                },
+                y: Vertical::ByCenter { y },
+            },
+        )));
+        let cost = sprites.len() - 1;
+        sprites.push(icon_plus(
+            &app.assets,
+            Position {
+                x: Horizontal::AtWindowCenterByCenter {
+                    offset: column + COLUMN_WIDTH / 2.0 - 40.0,
+                },
+                y: Vertical::ByCenter { y },
+            },
+            n as u8,
+        ));
+        let plus = sprites.len() - 1;
+
+        Row {
+            advance,
+            name,
+            cost,
+            plus,
+        }
+    }
+
+    fn update_rows(&mut self, ctx: &mut Context) {
+        let window_size = self.window_size;
+        let world = self.world.borrow();
+        let player = world.player();
+        let race = player.personality.appearance.race;
+        let char_sheet = &player.char_sheet;
+
+        let text = format!(
+            "Experience: {}, advance points: {} ({ADVANCE_POINTS} per Advance)",
+            char_sheet.experience, char_sheet.advance_points
+        );
+        self.sprites[self.points_label]
+            .as_label()
+            .unwrap()
+            .update(text, ctx, window_size);
+        let data = GameData::instance();
+        let hindrances: Vec<&str> = char_sheet
+            .hindrances
+            .iter()
+            .filter_map(|id| data.hindrances.get(id))
+            .map(|hindrance| hindrance.name.as_str())
+            .collect();
+        let text = if hindrances.is_empty() {
+            "No hindrances".to_string()
+        } else {
+            format!("Hindrances: {}", hindrances.join(", "))
+        };
+        self.sprites[self.hindrances_label]
+            .as_label()
+            .unwrap()
+            .update(text, ctx, window_size);
+
+        for row in &self.rows {
+            let (name, taken) = match row.advance {
+                Advance::Attribute(attribute) => (
+                    format!(
+                        "{} {}",
+                        attribute.name(),
+                        char_sheet.attributes.get_attribute(attribute).name()
+                    ),
+                    false,
+                ),
+                Advance::Skill(skill) => (
+                    format!(
+                        "{} {}",
+                        skill.name(),
+                        char_sheet.skills.get_skill(skill).name()
+                    ),
+                    false,
+                ),
+                Advance::Edge(id) => (
+                    data.edges[id].name.clone(),
+                    char_sheet.edges.iter().any(|e| e == id),
+                ),
+            };
+            let cost = char_sheet.advance_cost(race, row.advance);
+            let label = self.sprites[row.name].as_label().unwrap();
+            label.update(name, ctx, window_size);
+            label.set_color(if taken { TAKEN_COLOR } else { NOT_TAKEN_COLOR });
+            self.sprites[row.cost].as_label().unwrap().update(
+                cost.map(|cost| format!("costs {cost}")).unwrap_or_default(),
+                ctx,
+                window_size,
+            );
+            self.sprites[row.plus]
+                .as_button()
+                .unwrap()
+                .set_disabled(cost.is_none_or(|cost| cost > char_sheet.advance_points));
+        }
+    }
+}
+
+impl SceneImpl for CharacterSheet {
+    fn event(&mut self, _ctx: &mut Context, event: Event) -> SomeTransitions {
+        easy_back(&event, false)
+    }
+
+    fn on_open(&mut self, ctx: &mut Context) {
+        self.update_rows(ctx);
+    }
+
+    fn on_resize(&mut self, _ctx: &mut Context, window_size: (i32, i32)) {
+        self.window_size = window_size;
+    }
+
+    fn sprites(&self) -> SomeUISprites {
+        Some(&self.sprites)
+    }
+
+    fn sprites_mut(&mut self) -> SomeUISpritesMut {
+        Some(&mut self.sprites)
+    }
+
+    fn custom_event(&mut self, ctx: &mut Context, event: u8) -> SomeTransitions {
+        let advance = self.rows.get(event as usize)?.advance;
+        let mut world = self.world.borrow_mut();
+        let player = world.player_mut();
+        let race = player.personality.appearance.race;
+        player.char_sheet.advance(race, advance);
+        drop(world);
+        self.update_rows(ctx);
+        None
+    }
+}
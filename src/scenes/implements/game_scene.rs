@@ -4,15 +4,16 @@ use std::rc::Rc;
 
 use geometry::{Direction, Point, TwoDimDirection, Vec2};
 use tetra::graphics::Color;
+use tetra::input::Key;
 use tetra::Context;
 
-use crate::game::Item;
 use crate::scenes::map_view;
 use crate::{
     app::App,
     assets::Assets,
     colors::Colors,
     game::{Action, ActionType, World},
+    input,
     ui::{GameLog, Label, Position, SomeUISprites, SomeUISpritesMut, UiSprite, Vertical},
 };
 
@@ -40,9 +41,8 @@ impl GameScene {
             Colors::WHITE_SMOKE,
             Position::by_left_top(55.0, 8.0),
         ));
-        // TODO: custom calendar
         let current_time_label = Box::new(Label::new(
-            format!("{}", world.borrow().meta.current_tick),
+            Self::current_time(&world.borrow()),
             app.assets.fonts.default.clone(),
             Colors::WHITE_SMOKE,
             Position::horizontal_center(0.0, Vertical::ByTop { y: 5.0 }),
@@ -54,12 +54,7 @@ impl GameScene {
             Position::by_left_top(5.0, 40.0),
         ));
         let hands_display = Box::new(Label::new(
-            world
-                .borrow()
-                .player()
-                .wield
-                .first()
-                .map_or("empty", Item::name),
+            world.borrow().player().hands.display_name(),
             app.assets.fonts.default.clone(),
             Colors::WHITE_SMOKE,
             Position::by_left_top(65.0, 40.0),
@@ -126,19 +121,35 @@ impl GameScene {
 
     fn make_world_tick(&mut self, ctx: &mut Context) {
         self.world.borrow_mut().tick();
+        self.after_world_changed(ctx);
+    }
 
+    /// World doesn't go on until the player answers
+    fn benny_prompt_update(&mut self, ctx: &mut Context) {
+        let spend = if input::is_key_with_mod_pressed(ctx, Key::Y) {
+            true
+        } else if input::is_key_with_mod_pressed(ctx, Key::N)
+            || input::is_key_pressed(ctx, Key::Escape)
+        {
+            false
+        } else {
+            return;
+        };
+        self.world.borrow_mut().answer_benny_prompt(spend);
+        self.after_world_changed(ctx);
+    }
+
+    fn after_world_changed(&mut self, ctx: &mut Context) {
         for event in self.world.borrow().log().new_events() {
-            self.log.log(event.msg.as_str(), event.category.into());
+            self.log
+                .log_with_details(event.msg.as_str(), &event.details, event.category.into());
+        }
+        if let Some(prompt) = self.world.borrow().benny_prompt() {
+            let bennies = self.world.borrow().player().bennies;
+            self.log.log(prompt.question(bennies), Colors::GOLD);
         }
-        let current_time = format!("{}", self.world.borrow().meta.current_tick);
-        let hands_display = self
-            .world
-            .borrow()
-            .player()
-            .wield
-            .first()
-            .map_or("empty", |i| i.name())
-            .to_string();
+        let current_time = Self::current_time(&self.world.borrow());
+        let hands_display = self.world.borrow().player().hands.display_name();
         let window_size = self.window_size;
         self.current_time_label()
             .update(current_time, ctx, window_size);
@@ -146,6 +157,16 @@ impl GameScene {
             .update(hands_display, ctx, window_size);
     }
 
+    fn current_time(world: &World) -> String {
+        let now = world.now();
+        format!(
+            "{now}, {} week, {}, {}",
+            now.week_name(),
+            now.season().name(),
+            world.weather().name()
+        )
+    }
+
     fn hands_display_label(&mut self) -> &mut Label {
         self.sprites[2].as_label().unwrap()
     }
@@ -161,7 +182,11 @@ impl GameScene {
 
 impl SceneImpl for GameScene {
     fn on_update(&mut self, ctx: &mut Context) -> SomeTransitions {
-        if self.world.borrow().player().action.is_some() {
+        if self.world.borrow().benny_prompt().is_some() {
+            self.benny_prompt_update(ctx);
+
+            None
+        } else if self.world.borrow().player().action.is_some() {
             self.make_world_tick(ctx);
 
             None
@@ -192,6 +217,7 @@ impl SceneImpl for GameScene {
             Vec2::new(5.0, 5.0),
             3.0,
             false,
+            1.0,
             self.world.borrow().player(),
         );
         self.current_mode().borrow_mut().draw(ctx, self);
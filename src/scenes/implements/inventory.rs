@@ -0,0 +1,312 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use geometry::{Direction, Vec2};
+use tetra::{input::Key, Context, Event};
+
+use crate::{
+    app::App,
+    colors::Colors,
+    game::{
+        actions::implements::{Drop, PutIn, TakeOff, TakeOut, Wear, Wield},
+        Action, ActionType, Hand, Item, World,
+    },
+    ui::{
+        Alert, Button, Horizontal, ItemDisplay, Label, Position, Positionate, SomeUISprites,
+        SomeUISpritesMut, UiSprite, Vertical,
+    },
+};
+
+use super::super::{
+    helpers::{back_btn, easy_back, label},
+    SceneImpl, SomeTransitions, Transition,
+};
+
+const KEYS: [Key; 10] = [
+    Key::Num1,
+    Key::Num2,
+    Key::Num3,
+    Key::Num4,
+    Key::Num5,
+    Key::Num6,
+    Key::Num7,
+    Key::Num8,
+    Key::Num9,
+    Key::Num0,
+];
+const ROW_HEIGHT: f32 = 30.0;
+const WIDTH: f32 = 800.0;
+/// Space for the right-aligned buttons of a row
+const BUTTONS_WIDTH: f32 = 380.0;
+/// Right border of the mass column, between item names and buttons
+const MASS_RIGHT: f32 = WIDTH / 2.0 - 20.0 - BUTTONS_WIDTH - 10.0;
+
+type Sprites = Vec<Box<dyn UiSprite>>;
+
+enum Row {
+    Header(String),
+    Empty,
+    Item {
+        item: Box<Item>,
+        nested: bool,
+        actions: Vec<(&'static str, ActionType)>,
+    },
+}
+
+pub struct Inventory {
+    sprites: Sprites,
+    world: Rc<RefCell<World>>,
+    actions: Vec<ActionType>,
+}
+
+impl Inventory {
+    pub fn new(app: &App, ctx: &mut Context) -> Self {
+        let world = app.get_world();
+        let rows = Self::rows(&world.borrow());
+        let height = rows.len() as f32 * ROW_HEIGHT + 60.0;
+        let mut y = -height / 2.0;
+        let mut sprites: Sprites = Vec::with_capacity(rows.len() * 3 + 2);
+        let mut actions = Vec::new();
+
+        sprites.push(Box::new(Alert::new(
+            WIDTH,
+            height,
+            app.assets.alert.clone(),
+            Position::center(),
+        )));
+        for row in rows {
+            y += ROW_HEIGHT;
+            match row {
+                Row::Header(title) => sprites.push(label(
+                    title,
+                    &app.assets,
+                    Position {
+                        x: Horizontal::AtWindowCenterByLeft {
+                            offset: -WIDTH / 2.0 + 20.0,
+                        },
+                        y: Vertical::AtWindowCenterByBottom { offset: y },
+                    },
+                )),
+                Row::Empty => sprites.push(Box::new(Label::new(
+                    "(empty)",
+                    app.assets.fonts.default.clone(),
+                    Colors::DARK_GRAY,
+                    Position {
+                        x: Horizontal::AtWindowCenterByLeft {
+                            offset: -WIDTH / 2.0 + 40.0,
+                        },
+                        y: Vertical::AtWindowCenterByBottom { offset: y },
+                    },
+                ))),
+                Row::Item {
+                    item,
+                    nested,
+                    actions: row_actions,
+                } => {
+                    let x = -WIDTH / 2.0 + if nested { 60.0 } else { 40.0 };
+                    Self::push_sprites_for_item(&mut sprites, &item, x, y, app);
+                    let mut buttons = Vec::with_capacity(row_actions.len());
+                    for (text, typ) in row_actions {
+                        let possible = Action::new(0, typ, &world.borrow()).is_ok();
+                        let mut button = Self::action_btn(actions.len(), text, possible, app);
+                        let width = button.calc_size(ctx).x;
+                        buttons.push((button, width));
+                        actions.push(typ);
+                    }
+                    // buttons are aligned to the right border
+                    let mut right = WIDTH / 2.0 - 20.0;
+                    for (mut button, width) in buttons.into_iter().rev() {
+                        button.set_position(Position {
+                            x: Horizontal::AtWindowCenterByRight { offset: right },
+                            y: Vertical::AtWindowCenterByBottom { offset: y + 3.0 },
+                        });
+                        right -= width + 4.0;
+                        sprites.push(button);
+                    }
+                }
+            }
+        }
+        sprites.push(back_btn(
+            Position::horizontal_center(
+                0.0,
+                Vertical::AtWindowCenterByTop {
+                    offset: height / 2.0 - 10.0,
+                },
+            ),
+            &app.assets,
+        ));
+
+        Self {
+            sprites,
+            world,
+            actions,
+        }
+    }
+
+    fn push_sprites_for_item(sprites: &mut Sprites, item: &Item, x: f32, y: f32, app: &App) {
+        sprites.push(Box::new(ItemDisplay::new(
+            Some(item),
+            app.assets.fonts.default.clone(),
+            Colors::DARK_BROWN,
+            app.assets.tileset.clone(),
+            Vec2::new(2.0, 2.0),
+            Position {
+                x: Horizontal::AtWindowCenterByLeft { offset: x },
+                y: Vertical::AtWindowCenterByBottom { offset: y },
+            },
+        )));
+        sprites.push(Box::new(Label::new(
+            format!("{:.1} kg", item.mass() as f32 / 1000.0),
+            app.assets.fonts.default.clone(),
+            Colors::DARK_BROWN,
+            Position {
+                x: Horizontal::AtWindowCenterByRight { offset: MASS_RIGHT },
+                y: Vertical::AtWindowCenterByBottom { offset: y },
+            },
+        )));
+    }
+
+    fn action_btn(i: usize, text: &str, possible: bool, app: &App) -> Box<Button> {
+        Box::new(
+            Button::text(
+                if i < 10 { vec![KEYS[i].into()] } else { vec![] },
+                if i < 10 {
+                    format!("[{}] {text}", if i < 9 { i + 1 } else { 0 })
+                } else {
+                    text.to_string()
+                },
+                app.assets.fonts.default.clone(),
+                app.assets.button.clone(),
+                Position::center(),
+                Transition::CustomEvent(i as u8),
+            )
+            .with_disabled(!possible),
+        )
+    }
+
+    fn rows(world: &World) -> Vec<Row> {
+        let player = world.player();
+        let mut rows = vec![Row::Header("Hands".to_string())];
+        if player.hands.is_empty() {
+            rows.push(Row::Empty);
+        }
+        for (hand, item) in player.hands.iter() {
+            let mut actions = vec![(
+                "Drop",
+                Drop {
+                    hand,
+                    dir: Direction::Here,
+                }
+                .into(),
+            )];
+            if item.is_wearable() {
+                actions.push(("Wear", Wear { hand }.into()));
+            }
+            rows.push(Row::Item {
+                item: Box::new(item.clone()),
+                nested: false,
+                actions,
+            });
+        }
+
+        let busy_hands: Vec<Hand> = player.hands.iter().map(|(hand, _)| hand).collect();
+
+        rows.push(Row::Header(format!(
+            "Wear (armor {}, warmth {})",
+            player.wear.armor(),
+            player.wear.warmth()
+        )));
+        if player.wear.is_empty() {
+            rows.push(Row::Empty);
+        }
+        for (item_id, item) in player.wear.iter().enumerate() {
+            let mut actions = vec![("Take off", TakeOff { item_id }.into())];
+            if item.is_container() {
+                // items from both hands can be put in
+                for &hand in &busy_hands {
+                    let text = match (busy_hands.len(), hand) {
+                        (1, _) => "Put in",
+                        (_, Hand::Main) => "Put in (main)",
+                        (_, Hand::Off) => "Put in (off)",
+                    };
+                    actions.push((
+                        text,
+                        PutIn {
+                            hand,
+                            container_id: item_id,
+                        }
+                        .into(),
+                    ));
+                }
+            }
+            rows.push(Row::Item {
+                item: Box::new(item.clone()),
+                nested: false,
+                actions,
+            });
+            for (content_id, content) in item.contents().iter().enumerate() {
+                rows.push(Row::Item {
+                    item: Box::new(content.clone()),
+                    nested: true,
+                    actions: vec![(
+                        "Take out",
+                        TakeOut {
+                            container_id: item_id,
+                            item_id: content_id,
+                        }
+                        .into(),
+                    )],
+                });
+            }
+        }
+
+        rows.push(Row::Header("On the ground".to_string()));
+        let mut map = world.map();
+        let items = &map.get_tile(player.pos).items;
+        if items.is_empty() {
+            rows.push(Row::Empty);
+        }
+        // only the topmost item can be picked up
+        for (i, item) in items.iter().enumerate().rev() {
+            rows.push(Row::Item {
+                item: Box::new(item.clone()),
+                nested: false,
+                actions: if i == items.len() - 1 {
+                    vec![(
+                        "Pick up",
+                        Wield {
+                            dir: Direction::Here,
+                        }
+                        .into(),
+                    )]
+                } else {
+                    Vec::new()
+                },
+            });
+        }
+
+        rows
+    }
+}
+
+impl SceneImpl for Inventory {
+    fn event(&mut self, _ctx: &mut Context, event: Event) -> SomeTransitions {
+        easy_back(&event, false)
+    }
+
+    fn sprites(&self) -> SomeUISprites {
+        Some(&self.sprites)
+    }
+
+    fn sprites_mut(&mut self) -> SomeUISpritesMut {
+        Some(&mut self.sprites)
+    }
+
+    fn custom_event(&mut self, _ctx: &mut Context, event: u8) -> SomeTransitions {
+        let typ = *self.actions.get(event as usize)?;
+        let action = Action::new(0, typ, &self.world.borrow()).ok()?;
+        self.world.borrow_mut().player_mut().action = Some(action);
+
+        Some(vec![Transition::Pop])
+    }
+}
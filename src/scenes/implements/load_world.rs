@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use chrono::{DateTime, Local};
+use chrono::Local;
 use geometry::Vec2;
 use tetra::{
     graphics::{
@@ -14,6 +14,7 @@ use tetra::{
 use crate::{
     app::App,
     colors::Colors,
+    game::DateTime,
     savefile::{self, savefiles, savefiles_exists, Meta},
     ui::{
         Alert, Button, Horizontal, HoverableMesh, Label, Position, Positionate, SomeUISprites,
@@ -47,7 +48,7 @@ pub struct LoadWorld {
 impl LoadWorld {
     pub fn new(app: &App, ctx: &mut Context) -> Self {
         let savefiles = savefiles();
-        let mut sprites: Sprites = Vec::with_capacity(savefiles.len() * 6 + 1);
+        let mut sprites: Sprites = Vec::with_capacity(savefiles.len() * 7 + 1);
         let height = savefiles.len() as f32 * 50.0 + 33.0;
         // TODO: Add scroll if there are too many savefiles
         let mut y = -height / 2.0;
@@ -94,7 +95,7 @@ impl LoadWorld {
                 y: Vertical::AtWindowCenterByTop { offset: y },
             },
         )));
-        sprites.push(Box::new(Label::new(
+        let mut name_label = Box::new(Label::new(
             savefile.name.as_str(),
             app.assets.fonts.header.clone(),
             Colors::LIGHT_YELLOW,
@@ -102,6 +103,19 @@ impl LoadWorld {
                 x: Horizontal::AtWindowCenterByLeft { offset: -280.0 },
                 y: Vertical::AtWindowCenterByTop { offset: y - 2.0 },
             },
+        ));
+        let name_label_size = name_label.calc_size(ctx);
+        sprites.push(name_label);
+        sprites.push(Box::new(Label::new(
+            DateTime::from_tick(savefile.current_tick).to_string(),
+            app.assets.fonts.default.clone(),
+            Colors::LIGHT_YELLOW,
+            Position {
+                x: Horizontal::AtWindowCenterByLeft {
+                    offset: -270.0 + name_label_size.x,
+                },
+                y: Vertical::AtWindowCenterByTop { offset: y + 4.0 },
+            },
         )));
         let mut version_label = Box::new(Label::new(
             savefile.version.as_str(),
@@ -118,7 +132,7 @@ impl LoadWorld {
         ));
         let version_label_size = version_label.calc_size(ctx);
         sprites.push(version_label);
-        let time: DateTime<Local> = savefile.time.into();
+        let time: chrono::DateTime<Local> = savefile.time.into();
         sprites.push(Box::new(Label::new(
             time.format("%Y.%m.%d %H:%M:%S").to_string(),
             app.assets.fonts.default.clone(),
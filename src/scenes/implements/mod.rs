@@ -1,19 +1,25 @@
 pub use character_attributes::CharacterAttributes;
+pub use character_edges::CharacterEdges;
+pub use character_sheet::CharacterSheet;
 pub use create_character::CreateCharacter;
 pub use create_world::CreateWorld;
 pub use empty::Empty;
 pub use game_menu::GameMenu;
 pub use game_scene::GameScene;
+pub use inventory::Inventory;
 pub use load_world::LoadWorld;
 pub use main_menu::MainMenu;
 pub use settings_scene::SettingsScene;
 
 mod character_attributes;
+mod character_edges;
+mod character_sheet;
 mod create_character;
 mod create_world;
 mod empty;
 mod game_menu;
 mod game_scene;
+mod inventory;
 mod load_world;
 mod main_menu;
 mod settings_scene;
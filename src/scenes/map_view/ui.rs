@@ -5,9 +5,23 @@ use crate::ui::GameLog;
 
 pub fn draw_log(ctx: &mut Context, log: &mut GameLog) {
     let window_size = tetra::window::get_size(ctx);
-    for (i, msg) in log.texts.iter_mut().enumerate() {
+    let expanded = log.expanded;
+    // lines are drawn from the bottom, details go under their message
+    let mut line = 0;
+    for msg in &mut log.texts {
+        if expanded {
+            for i in (0..msg.details.len()).rev() {
+                line += 1;
+                msg.draw_detail(
+                    i,
+                    Vec2::new(30.0, window_size.1 as f32 - 20.0 * line as f32),
+                    ctx,
+                );
+            }
+        }
+        line += 1;
         msg.draw(
-            Vec2::new(10.0, window_size.1 as f32 - 20.0 * (i + 1) as f32),
+            Vec2::new(10.0, window_size.1 as f32 - 20.0 * line as f32),
             ctx,
         );
     }
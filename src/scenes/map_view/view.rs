@@ -13,6 +13,32 @@ use crate::game::{Avatar, World};
 
 // TODO: refactor this to small functions
 
+/// Brightness of tiles lit just enough to be seen
+const MIN_SHADE: f32 = 0.3;
+
+/// Darkens color according to light level of the tile
+fn shade(color: Color, light: f32) -> Color {
+    let k = MIN_SHADE + (1.0 - MIN_SHADE) * light.clamp(0.0, 1.0);
+    Color::rgba(color.r * k, color.g * k, color.b * k, color.a)
+}
+
+/// Remembered tiles are drawn dim and grayish
+const REMEMBERED_COLOR: Color = Color::rgb(0.3, 0.3, 0.35);
+
+/// Sprites bigger than a tile (like trees) stick out upwards
+fn terrain_params(assets: &Assets, looks_like: &str, position: Vec2, zoom: f32) -> DrawParams {
+    let this_tile_size = Tileset::get_size(looks_like);
+    let asset_tile_size = assets.tileset.tile_size as f32;
+    let x_correction = -(this_tile_size.x - asset_tile_size) / 2.0 * zoom;
+    let y_correction = -(this_tile_size.y - asset_tile_size) * zoom;
+    DrawParams::new()
+        .position(Vec2::new(
+            (position.x + x_correction).round(),
+            (position.y + y_correction).round(),
+        ))
+        .scale(Vec2::new(zoom, zoom))
+}
+
 pub fn draw(
     ctx: &mut Context,
     world: &RefCell<World>,
@@ -40,27 +66,35 @@ pub fn draw(
     let left_top = center_tile + (-window_size_in_tiles.0 / 2, -window_size_in_tiles.1 / 2);
     let right_bottom = center_tile + (window_size_in_tiles.0 / 2, window_size_in_tiles.1 / 2);
     world.map().load_tiles_between(left_top, right_bottom);
-    for (pos, tile) in world.map().tiles_between(left_top, right_bottom) {
-        if !world.is_visible(pos) {
-            continue; // TODO: TileView struct for remembering unseen tiles
-        }
+    let map = world.map();
+    for (pos, tile) in map.tiles_between(left_top, right_bottom) {
         let dx = pos.x - center_tile.x;
         let dy = pos.y - center_tile.y;
-        let this_tile_size = Tileset::get_size(tile.terrain.looks_like());
-        let asset_tile_size = assets.tileset.tile_size as f32;
-        let x_correction = -(this_tile_size.x - asset_tile_size) / 2.0 * zoom;
-        let y_correction = -(this_tile_size.y - asset_tile_size) * zoom;
-        let params = DrawParams::new()
-            .position(Vec2::new(
-                (center.x + dx as f32 * tile_size + x_correction).round(),
-                (center.y + dy as f32 * tile_size + y_correction).round(),
-            ))
-            .scale(scale);
+        let position = Vec2::new(
+            center.x + dx as f32 * tile_size,
+            center.y + dy as f32 * tile_size,
+        );
+        if !world.is_visible(pos) {
+            if let Some(memory) = map.remembered(pos) {
+                let params = terrain_params(assets, &memory.looks_like, position, zoom)
+                    .color(REMEMBERED_COLOR);
+                assets
+                    .tileset
+                    .draw_region(ctx, &memory.looks_like, params.clone());
+                if let Some(item) = &memory.item {
+                    assets.tileset.draw_region(ctx, &item.looks_like, params);
+                }
+            }
+            continue;
+        }
+        let light = world.light_level(pos);
+        let params = terrain_params(assets, tile.terrain.looks_like(), position, zoom)
+            .color(shade(Color::WHITE, light));
         assets
             .tileset
             .draw_region(ctx, tile.terrain.looks_like(), params.clone());
         if let Some(item) = tile.top_item() {
-            let item_params = params.clone().color(item.color());
+            let item_params = params.clone().color(shade(item.color(), light));
             assets
                 .tileset
                 .draw_region(ctx, item.look_like(), item_params);
@@ -68,17 +102,14 @@ pub fn draw(
                 assets.tileset.draw_region(ctx, "highlight", params);
             }
         }
-        let position = Vec2::new(
-            center.x + dx as f32 * tile_size,
-            center.y + dy as f32 * tile_size,
-        );
-        for i in tile.units.iter().copied() {
+        for i in tile.units.iter().copied().filter(|&i| world.is_noticed(i)) {
             draw_unit(
                 ctx,
                 &assets.tileset,
                 position,
                 zoom,
                 true,
+                light,
                 world.get_unit(i),
             );
         }
@@ -117,6 +148,7 @@ pub fn draw_unit(
     mut position: Vec2,
     zoom: f32,
     rotate: bool,
+    light: f32,
     avatar: &Avatar,
 ) {
     let scale = if !rotate || matches!(avatar.vision, TwoDimDirection::East) {
@@ -125,12 +157,17 @@ pub fn draw_unit(
         position.x += 10.0 * zoom;
         Vec2::new(-zoom, zoom)
     };
-    let mut draw_params = DrawParams::new().position(position).scale(scale);
-    if let Some(fur_color) = avatar.personality.appearance.fur_color {
-        draw_params = draw_params.color(fur_color.into());
-    }
+    let color = avatar
+        .personality
+        .appearance
+        .fur_color
+        .map_or(Color::WHITE, Color::from);
+    let draw_params = DrawParams::new()
+        .position(position)
+        .scale(scale)
+        .color(shade(color, light));
     tileset.draw_region(ctx, avatar.personality.appearance.race.name(), draw_params);
-    if let Some(item) = avatar.wield.get(0) {
+    if let Some(item) = avatar.hands.items().next() {
         let (offset_x, offset_y) = (
             if !rotate || matches!(avatar.vision, TwoDimDirection::East) {
                 5.0
@@ -148,7 +185,7 @@ pub fn draw_unit(
             item.look_like(),
             DrawParams::new()
                 .position(position + Vec2::new(offset_x, offset_y))
-                .color(item.color())
+                .color(shade(item.color(), light))
                 .scale(if item.tool_or_weapon() {
                     scale
                 } else {
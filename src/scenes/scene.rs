@@ -3,12 +3,12 @@ use std::path::PathBuf;
 use tetra::Context;
 
 use crate::app::App;
-use crate::game::races::Personality;
+use crate::game::{races::Personality, CharSheet};
 
 use super::{
     implements::{
-        CharacterAttributes, CreateCharacter, CreateWorld, Empty, GameMenu, GameScene, LoadWorld,
-        MainMenu, SettingsScene,
+        CharacterAttributes, CharacterEdges, CharacterSheet, CreateCharacter, CreateWorld, Empty,
+        GameMenu, GameScene, Inventory, LoadWorld, MainMenu, SettingsScene,
     },
     SceneImpl,
 };
@@ -23,8 +23,11 @@ pub enum Scene {
     LoadWorld,
     CreateCharacter(PathBuf),
     CharacterAttributes(PathBuf, Personality),
+    CharacterEdges(PathBuf, Personality, CharSheet),
     GameScene,
     GameMenu,
+    Inventory,
+    CharacterSheet,
 }
 
 impl Scene {
@@ -40,8 +43,17 @@ impl Scene {
             Scene::CharacterAttributes(path, personality) => {
                 Box::new(CharacterAttributes::new(&path, personality, app, ctx))
             }
+            Scene::CharacterEdges(path, personality, char_sheet) => Box::new(CharacterEdges::new(
+                &path,
+                personality,
+                char_sheet,
+                app,
+                ctx,
+            )),
             Scene::GameScene => Box::new(GameScene::new(app)),
             Scene::GameMenu => Box::new(GameMenu::new(app)),
+            Scene::Inventory => Box::new(Inventory::new(app, ctx)),
+            Scene::CharacterSheet => Box::new(CharacterSheet::new(app)),
         }
     }
 }
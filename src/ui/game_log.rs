@@ -5,10 +5,14 @@ use tetra::graphics::text::{Font, Text};
 use tetra::graphics::{Color, DrawParams};
 use tetra::Context;
 
+use crate::colors::Colors;
+
 #[derive(Debug)]
 pub struct LogMessageText {
     pub text: Text,
     pub color: Color,
+    /// Breakdowns of the rolls, shown when the log is expanded
+    pub details: Vec<Text>,
 }
 
 impl LogMessageText {
@@ -16,6 +20,7 @@ impl LogMessageText {
         Self {
             text: Text::new(content, font),
             color,
+            details: Vec::new(),
         }
     }
 
@@ -23,11 +28,22 @@ impl LogMessageText {
         self.text
             .draw(ctx, DrawParams::new().position(position).color(self.color));
     }
+
+    pub fn draw_detail(&mut self, i: usize, position: Vec2, ctx: &mut Context) {
+        if let Some(text) = self.details.get_mut(i) {
+            text.draw(
+                ctx,
+                DrawParams::new().position(position).color(Colors::GRAY),
+            );
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct GameLog {
     pub texts: VecDeque<LogMessageText>,
+    /// Show breakdowns of the rolls under the messages
+    pub expanded: bool,
     font: Font,
 }
 
@@ -37,16 +53,34 @@ impl GameLog {
     pub fn new(font: Font) -> Self {
         Self {
             texts: VecDeque::with_capacity(Self::TEXTS_LIMIT),
+            expanded: false,
             font,
         }
     }
 
     pub fn log<S: Into<String>>(&mut self, message: S, color: Color) {
+        self.log_with_details(message, &[], color);
+    }
+
+    pub fn log_with_details<S: Into<String>>(
+        &mut self,
+        message: S,
+        details: &[String],
+        color: Color,
+    ) {
         if self.texts.len() >= Self::TEXTS_LIMIT {
             self.texts.pop_back();
         }
-        self.texts
-            .push_front(LogMessageText::new(message, self.font.clone(), color));
+        let mut text = LogMessageText::new(message, self.font.clone(), color);
+        text.details = details
+            .iter()
+            .map(|d| Text::new(d.as_str(), self.font.clone()))
+            .collect();
+        self.texts.push_front(text);
+    }
+
+    pub fn toggle_details(&mut self) {
+        self.expanded = !self.expanded;
     }
 
     pub fn clear(&mut self) {